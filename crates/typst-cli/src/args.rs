@@ -74,6 +74,19 @@ pub struct CompileCommand {
     /// Produces a flamegraph of the compilation process
     #[arg(long = "flamegraph", value_name = "OUTPUT_SVG")]
     pub flamegraph: Option<Option<PathBuf>>,
+
+    /// Warns about common print problems when exporting a PDF
+    #[arg(long = "preflight")]
+    pub preflight: bool,
+
+    /// Additionally warns about colors that are not CMYK during preflight
+    #[arg(long = "preflight-cmyk", requires = "preflight")]
+    pub preflight_cmyk: bool,
+
+    /// Prints every application of a show rule with the rule and the element
+    /// it was applied to
+    #[arg(long = "trace-rules")]
+    pub trace_rules: bool,
 }
 
 /// Processes an input file to extract provided metadata
@@ -125,6 +138,20 @@ pub struct SharedArgs {
     )]
     pub font_paths: Vec<PathBuf>,
 
+    /// Raises or lowers the maximum depth of nested function calls
+    #[clap(long = "max-call-depth", value_name = "DEPTH")]
+    pub max_call_depth: Option<usize>,
+
+    /// Raises or lowers the maximum number of iterations of a while loop
+    #[clap(long = "max-iterations", value_name = "COUNT")]
+    pub max_iterations: Option<usize>,
+
+    /// Replaces images that fail to decode with a placeholder and a warning
+    /// instead of failing the compilation, and warns about fonts that fail to
+    /// load
+    #[clap(long)]
+    pub lenient: bool,
+
     /// The format to emit diagnostics in
     #[clap(
         long,
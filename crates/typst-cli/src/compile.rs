@@ -7,12 +7,13 @@ use codespan_reporting::term::{self, termcolor};
 use ecow::eco_format;
 use termcolor::{ColorChoice, StandardStream};
 use typst::diag::{bail, At, Severity, SourceDiagnostic, StrResult};
-use typst::eval::Tracer;
-use typst::foundations::Datetime;
+use typst::eval::{Progress, RuleApplication, Tracer};
+use typst::foundations::{Datetime, Repr, Target};
 use typst::model::Document;
 use typst::syntax::{FileId, Source, Span};
 use typst::visualize::Color;
 use typst::{World, WorldExt};
+use typst_pdf::PreflightOptions;
 
 use crate::args::{CompileCommand, DiagnosticFormat, OutputFormat};
 use crate::watch::Status;
@@ -53,11 +54,21 @@ impl CompileCommand {
             OutputFormat::Pdf
         })
     }
+
+    /// The export target reported to the document, derived from the output
+    /// format.
+    pub fn target(&self) -> StrResult<Target> {
+        Ok(match self.output_format()? {
+            OutputFormat::Pdf | OutputFormat::Svg => Target::Paged,
+            OutputFormat::Png => Target::Raster,
+        })
+    }
 }
 
 /// Execute a compilation command.
 pub fn compile(mut command: CompileCommand) -> StrResult<()> {
     let mut world = SystemWorld::new(&command.common)?;
+    world.set_target(command.target()?);
     compile_once(&mut world, &mut command, false)?;
     Ok(())
 }
@@ -93,13 +104,35 @@ pub fn compile_once(
         return Ok(());
     }
 
+    let progress = |progress: Progress| tracing::info!("Progress: {progress:?}");
     let mut tracer = Tracer::new();
+    tracer.set_lenient(command.common.lenient);
+    tracer.set_trace_rules(command.trace_rules);
+    tracer.set_progress(progress);
     let result = typst::compile(world, &mut tracer);
-    let warnings = tracer.warnings();
+
+    if command.trace_rules {
+        print_rule_trace(world, tracer.rules(), command.common.diagnostic_format)
+            .map_err(|err| eco_format!("failed to print rule trace ({err})"))?;
+    }
+
+    let mut warnings = tracer.warnings();
+
+    // Check for print problems.
+    if let Ok(document) = &result {
+        if command.preflight && command.output_format()? == OutputFormat::Pdf {
+            let options = PreflightOptions {
+                cmyk: command.preflight_cmyk,
+                ..PreflightOptions::default()
+            };
+            warnings.extend(typst_pdf::preflight(document, &options));
+        }
+    }
 
     match result {
         // Export the PDF / PNG.
         Ok(document) => {
+            progress(Progress::Exporting);
             export(world, &document, command, watching)?;
             let duration = start.elapsed();
 
@@ -270,16 +303,7 @@ pub fn print_diagnostics(
     warnings: &[SourceDiagnostic],
     diagnostic_format: DiagnosticFormat,
 ) -> Result<(), codespan_reporting::files::Error> {
-    let mut w = match diagnostic_format {
-        DiagnosticFormat::Human => color_stream(),
-        DiagnosticFormat::Short => StandardStream::stderr(ColorChoice::Never),
-    };
-
-    let mut config = term::Config { tab_width: 2, ..Default::default() };
-    if diagnostic_format == DiagnosticFormat::Short {
-        config.display_style = term::DisplayStyle::Short;
-    }
-
+    let (mut w, config) = terminal(diagnostic_format);
     for diagnostic in warnings.iter().chain(errors) {
         let diag = match diagnostic.severity {
             Severity::Error => Diagnostic::error(),
@@ -311,6 +335,49 @@ pub fn print_diagnostics(
     Ok(())
 }
 
+/// Print the recorded applications of show rules to the terminal.
+fn print_rule_trace(
+    world: &SystemWorld,
+    rules: &[RuleApplication],
+    diagnostic_format: DiagnosticFormat,
+) -> Result<(), codespan_reporting::files::Error> {
+    let (mut w, config) = terminal(diagnostic_format);
+    for application in rules {
+        let selector = application
+            .selector
+            .as_ref()
+            .map_or_else(|| "everything".into(), Repr::repr);
+        let labels = [
+            label(world, application.target).map(|l| l.with_message("matched element")),
+            label(world, application.rule)
+                .map(|l| Label::secondary(l.file_id, l.range).with_message("show rule")),
+        ];
+
+        let diag = Diagnostic::note()
+            .with_message(eco_format!("show rule for {selector} was applied"))
+            .with_labels(labels.into_iter().flatten().collect());
+
+        term::emit(&mut w, &config, world, &diag)?;
+    }
+
+    Ok(())
+}
+
+/// Create the output stream and configuration for printing diagnostics.
+fn terminal(diagnostic_format: DiagnosticFormat) -> (StandardStream, term::Config) {
+    let w = match diagnostic_format {
+        DiagnosticFormat::Human => color_stream(),
+        DiagnosticFormat::Short => StandardStream::stderr(ColorChoice::Never),
+    };
+
+    let mut config = term::Config { tab_width: 2, ..Default::default() };
+    if diagnostic_format == DiagnosticFormat::Short {
+        config.display_style = term::DisplayStyle::Short;
+    }
+
+    (w, config)
+}
+
 /// Create a label for a span.
 fn label(world: &SystemWorld, span: Span) -> Option<Label<FileId>> {
     Some(Label::primary(span.id()?, world.range(span)?))
@@ -7,6 +7,7 @@ use typst::diag::StrResult;
 use typst::text::{Font, FontBook, FontInfo, FontVariant};
 
 use crate::args::FontsCommand;
+use crate::print_warning;
 
 /// Execute a font listing command.
 pub fn fonts(command: &FontsCommand) -> StrResult<()> {
@@ -32,6 +33,8 @@ pub struct FontSearcher {
     pub book: FontBook,
     /// Slots that the fonts are loaded into.
     pub fonts: Vec<FontSlot>,
+    /// Whether to warn about fonts that fail to parse or load.
+    lenient: bool,
 }
 
 /// Holds details about the location of a font and lazily the font itself.
@@ -43,15 +46,30 @@ pub struct FontSlot {
     index: u32,
     /// The lazily loaded font.
     font: OnceCell<Option<Font>>,
+    /// Whether to warn if the font fails to load.
+    lenient: bool,
 }
 
 impl FontSlot {
     /// Get the font for this slot.
+    ///
+    /// If the font cannot be loaded, text falls back to other fonts. In
+    /// lenient mode, a warning is printed in that case.
     pub fn get(&self) -> Option<Font> {
         self.font
             .get_or_init(|| {
-                let data = fs::read(&self.path).ok()?.into();
-                Font::new(data, self.index)
+                let font = fs::read(&self.path)
+                    .ok()
+                    .and_then(|data| Font::new(data.into(), self.index));
+                if font.is_none() && self.lenient {
+                    // Failing to print the warning must not abort compilation.
+                    let _ = print_warning(&format!(
+                        "failed to load font {} (index {}), skipping it",
+                        self.path.display(),
+                        self.index,
+                    ));
+                }
+                font
             })
             .clone()
     }
@@ -60,7 +78,17 @@ impl FontSlot {
 impl FontSearcher {
     /// Create a new, empty system searcher.
     pub fn new() -> Self {
-        Self { book: FontBook::new(), fonts: vec![] }
+        Self {
+            book: FontBook::new(),
+            fonts: vec![],
+            lenient: false,
+        }
+    }
+
+    /// Whether to warn about fonts that fail to parse or load instead of
+    /// skipping them silently.
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
     }
 
     /// Search everything that is available.
@@ -87,14 +115,24 @@ impl FontSearcher {
                 .with_face_data(face.id, FontInfo::new)
                 .expect("database must contain this font");
 
-            if let Some(info) = info {
-                self.book.push(info);
-                self.fonts.push(FontSlot {
-                    path: path.clone(),
-                    index: face.index,
-                    font: OnceCell::new(),
-                });
-            }
+            let Some(info) = info else {
+                if self.lenient {
+                    let _ = print_warning(&format!(
+                        "failed to parse font {} (index {}), skipping it",
+                        path.display(),
+                        face.index,
+                    ));
+                }
+                continue;
+            };
+
+            self.book.push(info);
+            self.fonts.push(FontSlot {
+                path: path.clone(),
+                index: face.index,
+                font: OnceCell::new(),
+                lenient: self.lenient,
+            });
         }
 
         // Embedded fonts have lowest priority.
@@ -113,6 +151,7 @@ impl FontSearcher {
                     path: PathBuf::new(),
                     index: i as u32,
                     font: OnceCell::from(Some(font)),
+                    lenient: false,
                 });
             }
         };
@@ -72,6 +72,18 @@ fn print_error(msg: &str) -> io::Result<()> {
     writeln!(w, ": {msg}.")
 }
 
+/// Print an application-level warning (independent from a source file).
+fn print_warning(msg: &str) -> io::Result<()> {
+    let mut w = color_stream();
+    let styles = term::Styles::default();
+
+    w.set_color(&styles.header_warning)?;
+    write!(w, "warning")?;
+
+    w.reset()?;
+    writeln!(w, ": {msg}.")
+}
+
 /// Get stderr with color support if desirable.
 fn color_stream() -> termcolor::StandardStream {
     termcolor::StandardStream::stderr(if std::io::stderr().is_terminal() {
@@ -23,6 +23,7 @@ pub fn query(command: &QueryCommand) -> StrResult<()> {
     world.source(world.main()).map_err(|err| err.to_string())?;
 
     let mut tracer = Tracer::new();
+    tracer.set_lenient(command.common.lenient);
     let result = typst::compile(&world, &mut tracer);
     let warnings = tracer.warnings();
 
@@ -76,10 +77,9 @@ fn retrieve(
     .cast::<LocatableSelector>()?;
 
     Ok(document
-        .introspector
         .query(&selector.0)
         .into_iter()
-        .map(|x| x.into_inner())
+        .map(|(elem, _)| elem)
         .collect::<Vec<_>>())
 }
 
@@ -18,6 +18,7 @@ use crate::world::SystemWorld;
 pub fn watch(mut command: CompileCommand) -> StrResult<()> {
     // Create the world that serves sources, files, and fonts.
     let mut world = SystemWorld::new(&command.common)?;
+    world.set_target(command.target()?);
 
     // Perform initial compilation.
     compile_once(&mut world, &mut command, true)?;
@@ -7,7 +7,7 @@ use chrono::{DateTime, Datelike, Local};
 use comemo::Prehashed;
 use ecow::eco_format;
 use typst::diag::{FileError, FileResult, StrResult};
-use typst::foundations::{Bytes, Datetime};
+use typst::foundations::{Bytes, Datetime, Target};
 use typst::layout::Frame;
 use typst::syntax::{FileId, Source, VirtualPath};
 use typst::text::{Font, FontBook};
@@ -48,6 +48,7 @@ impl SystemWorld {
     /// Create a new system world.
     pub fn new(command: &SharedArgs) -> StrResult<Self> {
         let mut searcher = FontSearcher::new();
+        searcher.set_lenient(command.lenient);
         searcher.search(&command.font_paths);
 
         // Resolve the system-global input path.
@@ -71,12 +72,21 @@ impl SystemWorld {
         let main_path = VirtualPath::within_root(&input, &root)
             .ok_or("input file must be contained in project root")?;
 
+        // Apply the configured evaluation limits.
+        let mut library = Library::build();
+        if let Some(depth) = command.max_call_depth {
+            library.limits.call_depth = depth;
+        }
+        if let Some(iterations) = command.max_iterations {
+            library.limits.iterations = iterations;
+        }
+
         Ok(Self {
             workdir: std::env::current_dir().ok(),
             input,
             root,
             main: FileId::new(None, main_path),
-            library: Prehashed::new(Library::build()),
+            library: Prehashed::new(library),
             book: Prehashed::new(searcher.book),
             fonts: searcher.fonts,
             slots: RefCell::default(),
@@ -128,6 +138,13 @@ impl SystemWorld {
         self.source(id).expect("file id does not point to any source file")
     }
 
+    /// Set the export target that the `target` function reports.
+    pub fn set_target(&mut self, target: Target) {
+        let mut library = self.library.clone().into_inner();
+        library.target = target;
+        self.library = Prehashed::new(library);
+    }
+
     /// Gets access to the export cache.
     pub fn export_cache(&mut self) -> &mut ExportCache {
         &mut self.export_cache
@@ -3,11 +3,18 @@
 mod analyze;
 mod complete;
 mod jump;
+mod search;
+mod select;
 mod tooltip;
 
+#[cfg(test)]
+mod tests;
+
 pub use self::analyze::analyze_labels;
 pub use self::complete::{autocomplete, Completion, CompletionKind};
 pub use self::jump::{jump_from_click, jump_from_cursor, Jump};
+pub use self::search::{search, SearchMatch};
+pub use self::select::{selection_rects, span_rects, PageRect};
 pub use self::tooltip::{tooltip, Tooltip};
 
 use std::fmt::Write;
@@ -0,0 +1,191 @@
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use ecow::EcoString;
+use typst::foundations::StrPattern;
+use typst::layout::{Abs, Frame, FrameItem, Point, Size, Transform};
+use typst::model::Document;
+
+use crate::select::{bounding_rect, merge, PageRect};
+
+/// An occurrence of a search pattern in the document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchMatch {
+    /// The matched text.
+    pub text: EcoString,
+    /// The areas covered by the match, one per line.
+    pub rects: Vec<PageRect>,
+}
+
+/// Search the text of a finished document for a string or regular expression.
+///
+/// Each page is searched separately, with its text in reading order. Lines
+/// are joined by a single space, so a match can span a line break.
+pub fn search(document: &Document, pattern: &StrPattern) -> Vec<SearchMatch> {
+    let mut matches = vec![];
+    for (i, frame) in document.pages.iter().enumerate() {
+        let page = NonZeroUsize::new(i + 1).unwrap();
+        let mut text = PageText::new(page);
+        text.collect(frame, Transform::identity());
+
+        let ranges: Vec<Range<usize>> = match pattern {
+            StrPattern::Str(needle) if needle.is_empty() => vec![],
+            StrPattern::Str(needle) => text
+                .text
+                .match_indices(needle.as_str())
+                .map(|(start, m)| start..start + m.len())
+                .collect(),
+            StrPattern::Regex(regex) => regex
+                .find_iter(&text.text)
+                .filter(|m| !m.as_str().is_empty())
+                .map(|m| m.range())
+                .collect(),
+        };
+
+        for range in ranges {
+            let mut rects = vec![];
+            for glyph in &text.glyphs {
+                if glyph.range.start < range.end && range.start < glyph.range.end {
+                    merge(&mut rects, glyph.rect);
+                }
+            }
+
+            matches.push(SearchMatch { text: text.text[range].into(), rects });
+        }
+    }
+    matches
+}
+
+/// The text of a page along with the placement of its glyphs.
+struct PageText {
+    page: NonZeroUsize,
+    text: String,
+    glyphs: Vec<PlacedGlyph>,
+    baseline: Option<Abs>,
+}
+
+/// A glyph and the part of the page's text it displays.
+struct PlacedGlyph {
+    range: Range<usize>,
+    rect: PageRect,
+}
+
+impl PageText {
+    /// Create an empty text for a page.
+    fn new(page: NonZeroUsize) -> Self {
+        Self {
+            page,
+            text: String::new(),
+            glyphs: vec![],
+            baseline: None,
+        }
+    }
+
+    /// Collect the text in a frame.
+    fn collect(&mut self, frame: &Frame, ts: Transform) {
+        for (pos, item) in frame.items() {
+            let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+            match item {
+                FrameItem::Group(group) => {
+                    self.collect(&group.frame, ts.pre_concat(group.transform));
+                }
+
+                FrameItem::Text(text) => {
+                    let baseline = Point::zero().transform(ts).y;
+                    if self.baseline.is_some_and(|prev| prev != baseline) {
+                        self.text.push(' ');
+                    }
+                    self.baseline = Some(baseline);
+
+                    let offset = self.text.len();
+                    self.text.push_str(&text.text);
+
+                    let mut x = Abs::zero();
+                    for glyph in &text.glyphs {
+                        let width = glyph.x_advance.at(text.size);
+                        let range = glyph.range();
+                        self.glyphs.push(PlacedGlyph {
+                            range: offset + range.start..offset + range.end,
+                            rect: bounding_rect(
+                                self.page,
+                                ts,
+                                Point::new(x, -text.size),
+                                Size::new(width, text.size),
+                            ),
+                        });
+                        x += width;
+                    }
+                }
+
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typst::foundations::Regex;
+
+    use super::*;
+    use crate::tests::TestWorld;
+
+    /// Compile the text and search it for the pattern.
+    fn find(text: &str, pattern: StrPattern) -> Vec<SearchMatch> {
+        search(&TestWorld::new(text).compile(), &pattern)
+    }
+
+    /// Create a string pattern.
+    fn string(needle: &str) -> StrPattern {
+        StrPattern::Str(needle.into())
+    }
+
+    /// Create a regular expression pattern.
+    fn regex(re: &str) -> StrPattern {
+        StrPattern::Regex(Regex::new(re).unwrap())
+    }
+
+    #[test]
+    fn test_search_reports_pages() {
+        let matches = find("foo bar\n#pagebreak()\nbaz foo", string("foo"));
+        assert_eq!(matches.len(), 2);
+        for (m, page) in matches.iter().zip([1, 2]) {
+            assert_eq!(m.text, "foo");
+            assert_eq!(m.rects.len(), 1);
+            assert_eq!(m.rects[0].page.get(), page);
+        }
+        assert!(matches[1].rects[0].point.x > matches[0].rects[0].point.x);
+    }
+
+    #[test]
+    fn test_search_with_regex() {
+        let matches = find("bar baz bor", regex("b.r"));
+        let texts: Vec<_> = matches.iter().map(|m| m.text.as_str()).collect();
+        assert_eq!(texts, ["bar", "bor"]);
+        assert!(find("bar", regex("x*")).is_empty());
+        assert!(find("bar", string("")).is_empty());
+    }
+
+    #[test]
+    fn test_search_across_lines() {
+        let matches = find("alpha \\ beta", regex(r"alpha\s+beta"));
+        assert_eq!(matches.len(), 1);
+        let rects = &matches[0].rects;
+        assert_eq!(rects.len(), 2);
+        assert!(rects[0].point.y < rects[1].point.y);
+    }
+
+    #[test]
+    fn test_search_in_transformed_groups() {
+        let rect = |dx: &str| {
+            let matches = find(&format!("#move(dx: {dx})[foo]"), string("foo"));
+            assert_eq!(matches.len(), 1);
+            matches[0].rects[0]
+        };
+
+        let plain = rect("0pt");
+        let moved = rect("10pt");
+        assert!(moved.point.x.approx_eq(plain.point.x + Abs::pt(10.0)));
+        assert!(moved.point.y.approx_eq(plain.point.y));
+    }
+}
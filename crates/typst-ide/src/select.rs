@@ -0,0 +1,227 @@
+use std::num::NonZeroUsize;
+use std::ops::Range;
+
+use typst::layout::{Abs, Frame, FrameItem, Point, Size, Transform};
+use typst::model::Document;
+use typst::syntax::{Source, Span, SyntaxKind};
+use typst::text::Glyph;
+use typst::World;
+
+/// A rectangular area on a page.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PageRect {
+    /// The page, starting at 1.
+    pub page: NonZeroUsize,
+    /// The top-left corner of the rectangle.
+    pub point: Point,
+    /// The size of the rectangle.
+    pub size: Size,
+}
+
+/// Find the areas in the document that display text produced by the given
+/// byte range of a source file.
+///
+/// Neighbouring glyphs on the same line are merged into a single rectangle,
+/// so a selection spanning three lines typically yields three rectangles.
+/// Glyphs in transformed groups yield their bounding boxes on the page.
+pub fn selection_rects(
+    document: &Document,
+    source: &Source,
+    range: Range<usize>,
+) -> Vec<PageRect> {
+    let mut rects = vec![];
+    for (i, frame) in document.pages.iter().enumerate() {
+        let page = NonZeroUsize::new(i + 1).unwrap();
+        select_in_frame(&mut rects, page, frame, Transform::identity(), source, &range);
+    }
+    rects
+}
+
+/// Find the areas in the document that display text produced by the syntax
+/// node with the given span, for example the span of a piece of content.
+pub fn span_rects(world: &dyn World, document: &Document, span: Span) -> Vec<PageRect> {
+    let Some(source) = span.id().and_then(|id| world.source(id).ok()) else {
+        return vec![];
+    };
+
+    let Some(node) = source.find(span) else { return vec![] };
+    selection_rects(document, &source, node.range())
+}
+
+/// Collect the selected glyphs in a frame.
+fn select_in_frame(
+    rects: &mut Vec<PageRect>,
+    page: NonZeroUsize,
+    frame: &Frame,
+    ts: Transform,
+    source: &Source,
+    range: &Range<usize>,
+) {
+    for (pos, item) in frame.items() {
+        let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+        match item {
+            FrameItem::Group(group) => {
+                let ts = ts.pre_concat(group.transform);
+                select_in_frame(rects, page, &group.frame, ts, source, range);
+            }
+
+            FrameItem::Text(text) => {
+                let mut x = Abs::zero();
+                for glyph in &text.glyphs {
+                    let width = glyph.x_advance.at(text.size);
+                    if glyph_range(source, glyph).is_some_and(|glyph| {
+                        glyph.start < range.end && range.start < glyph.end
+                    }) {
+                        let point = Point::new(x, -text.size);
+                        let size = Size::new(width, text.size);
+                        merge(rects, bounding_rect(page, ts, point, size));
+                    }
+                    x += width;
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+/// The byte range in the source file that produced the glyph.
+pub(crate) fn glyph_range(source: &Source, glyph: &Glyph) -> Option<Range<usize>> {
+    let (span, offset) = glyph.span;
+    if span.id() != Some(source.id()) {
+        return None;
+    }
+
+    let node = source.find(span)?;
+    if node.kind() == SyntaxKind::Text {
+        let start = node.offset() + usize::from(offset);
+        Some(start..start + glyph.range().len())
+    } else {
+        Some(node.range())
+    }
+}
+
+/// Compute the bounding box of a transformed area on a page.
+pub(crate) fn bounding_rect(
+    page: NonZeroUsize,
+    ts: Transform,
+    pos: Point,
+    size: Size,
+) -> PageRect {
+    let mut min = Point::splat(Abs::inf());
+    let mut max = Point::splat(-Abs::inf());
+    for point in [
+        pos,
+        pos + Point::with_x(size.x),
+        pos + Point::with_y(size.y),
+        pos + size.to_point(),
+    ] {
+        let t = point.transform(ts);
+        min = min.min(t);
+        max = max.max(t);
+    }
+
+    PageRect { page, point: min, size: (max - min).to_size() }
+}
+
+/// Add a rectangle, merging it with the previous one if both are on the same
+/// line.
+pub(crate) fn merge(rects: &mut Vec<PageRect>, rect: PageRect) {
+    if let Some(last) = rects.last_mut() {
+        let last_bottom = last.point.y + last.size.y;
+        let rect_bottom = rect.point.y + rect.size.y;
+        if last.page == rect.page
+            && rect.point.x >= last.point.x
+            && rect.point.y < last_bottom
+            && last.point.y < rect_bottom
+        {
+            let min = last.point.min(rect.point);
+            let max = Point::new(
+                (last.point.x + last.size.x).max(rect.point.x + rect.size.x),
+                last_bottom.max(rect_bottom),
+            );
+            last.point = min;
+            last.size = (max - min).to_size();
+            return;
+        }
+    }
+
+    rects.push(rect);
+}
+
+#[cfg(test)]
+mod tests {
+    use typst::syntax::LinkedNode;
+
+    use super::*;
+    use crate::tests::TestWorld;
+
+    /// Compile the text and select the last occurrence of the needle.
+    fn select(text: &str, needle: &str) -> Vec<PageRect> {
+        let world = TestWorld::new(text);
+        let start = text.rfind(needle).unwrap();
+        selection_rects(&world.compile(), &world.source, start..start + needle.len())
+    }
+
+    /// Select an `X` placed in a transformed container.
+    fn select_transformed(container: &str) -> PageRect {
+        let rects = select(
+            &format!(
+                "#set page(width: auto, height: auto, margin: 0pt)\n#{container}[X]"
+            ),
+            "X",
+        );
+        assert_eq!(rects.len(), 1);
+        rects[0]
+    }
+
+    #[test]
+    fn test_selection_rects_are_merged_per_line() {
+        let rects = select(
+            "#set page(width: 80pt, height: auto)\n\
+             A paragraph that spans over several lines.",
+            "A paragraph that spans over several lines.",
+        );
+
+        assert!(rects.len() > 1);
+        assert!(rects.windows(2).all(|w| w[0].point.y < w[1].point.y));
+        assert!(rects.iter().all(|rect| rect.page.get() == 1));
+        assert_eq!(select("A paragraph", "paragraph").len(), 1);
+    }
+
+    #[test]
+    fn test_selection_rects_of_content_span() {
+        let text = "Hello *World*";
+        let world = TestWorld::new(text);
+        let document = world.compile();
+
+        let root = LinkedNode::new(world.source.root());
+        let leaf = root.leaf_at(text.find("World").unwrap() + 1).unwrap();
+        let strong = leaf.parent().and_then(|markup| markup.parent()).unwrap();
+        assert_eq!(strong.kind(), SyntaxKind::Strong);
+
+        let rects = span_rects(&world, &document, strong.span());
+        assert_eq!(rects.len(), 1);
+        assert_eq!(rects, select(text, "World"));
+        assert!(rects[0].point.x > select(text, "Hello")[0].point.x);
+    }
+
+    #[test]
+    fn test_selection_rects_are_transformed() {
+        let plain = select_transformed("move(dx: 0pt)");
+
+        let moved = select_transformed("move(dx: 10pt, dy: 5pt)");
+        assert!(moved.point.x.approx_eq(plain.point.x + Abs::pt(10.0)));
+        assert!(moved.point.y.approx_eq(plain.point.y + Abs::pt(5.0)));
+        assert!(moved.size.x.approx_eq(plain.size.x));
+
+        let scaled = select_transformed("scale(x: 200%, origin: top + left)");
+        assert!(scaled.point.x.approx_eq(plain.point.x));
+        assert!(scaled.size.x.approx_eq(plain.size.x * 2.0));
+        assert!(scaled.size.y.approx_eq(plain.size.y));
+
+        let rotated = select_transformed("rotate(90deg, origin: top + left)");
+        assert!(rotated.size.x.approx_eq(plain.size.y));
+        assert!(rotated.size.y.approx_eq(plain.size.x));
+    }
+}
@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use comemo::Prehashed;
+use typst::diag::{FileError, FileResult};
+use typst::eval::Tracer;
+use typst::foundations::{Bytes, Datetime};
+use typst::model::Document;
+use typst::syntax::{FileId, Source, VirtualPath};
+use typst::text::{Font, FontBook};
+use typst::{Library, World};
+
+/// A world with a single in-memory source file and the fonts from the
+/// repository's assets.
+pub struct TestWorld {
+    pub source: Source,
+    library: Prehashed<Library>,
+    base: &'static TestBase,
+}
+
+/// The fonts shared by all test worlds.
+struct TestBase {
+    book: Prehashed<FontBook>,
+    fonts: Vec<Font>,
+}
+
+impl TestWorld {
+    /// Create a world with the given main source text.
+    pub fn new(text: &str) -> Self {
+        static BASE: OnceLock<TestBase> = OnceLock::new();
+        let base = BASE.get_or_init(|| {
+            let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets/fonts");
+            let mut paths: Vec<_> = fs::read_dir(dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .collect();
+            paths.sort();
+
+            let fonts: Vec<_> = paths
+                .iter()
+                .flat_map(|path| Font::iter(fs::read(path).unwrap().into()))
+                .collect();
+
+            TestBase {
+                book: Prehashed::new(FontBook::from_fonts(&fonts)),
+                fonts,
+            }
+        });
+
+        let id = FileId::new(None, VirtualPath::new("main.typ"));
+        Self {
+            source: Source::new(id, text.into()),
+            library: Prehashed::new(Library::build()),
+            base,
+        }
+    }
+
+    /// Compile the main source into a document.
+    pub fn compile(&self) -> Document {
+        typst::compile(self, &mut Tracer::new()).unwrap()
+    }
+}
+
+impl World for TestWorld {
+    fn library(&self) -> &Prehashed<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &Prehashed<FontBook> {
+        &self.base.book
+    }
+
+    fn main(&self) -> Source {
+        self.source.clone()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        if id == self.source.id() {
+            Ok(self.source.clone())
+        } else {
+            Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+        }
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        Err(FileError::NotFound(id.vpath().as_rootless_path().into()))
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.base.fonts.get(index).cloned()
+    }
+
+    fn today(&self, _: Option<i64>) -> Option<Datetime> {
+        Some(Datetime::from_ymd(1970, 1, 1).unwrap())
+    }
+}
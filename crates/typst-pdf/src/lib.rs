@@ -8,6 +8,9 @@ mod image;
 mod outline;
 mod page;
 mod pattern;
+mod preflight;
+
+pub use self::preflight::{preflight, PreflightOptions};
 
 use std::cmp::Eq;
 use std::collections::{BTreeMap, HashMap};
@@ -17,8 +20,9 @@ use std::sync::Arc;
 use base64::Engine;
 use ecow::{eco_format, EcoString};
 use pdf_writer::types::Direction;
-use pdf_writer::{Finish, Name, Pdf, Ref, TextStr};
-use typst::foundations::Datetime;
+use pdf_writer::writers::Destination;
+use pdf_writer::{Finish, Name, Pdf, Ref, Str, TextStr};
+use typst::foundations::{Datetime, Label};
 use typst::layout::{Abs, Dir, Em, Transform};
 use typst::model::Document;
 use typst::text::{Font, Lang};
@@ -90,6 +94,9 @@ struct PdfContext<'a> {
     page_tree_ref: Ref,
     /// The IDs of written pages.
     page_refs: Vec<Ref>,
+    /// The IDs of the pages' content streams, which are written as soon as a
+    /// page is constructed.
+    page_content_refs: Vec<Ref>,
     /// The IDs of written fonts.
     font_refs: Vec<Ref>,
     /// The IDs of written images.
@@ -100,6 +107,13 @@ struct PdfContext<'a> {
     pattern_refs: Vec<Ref>,
     /// The IDs of written external graphics states.
     ext_gs_refs: Vec<Ref>,
+    /// The IDs of written form fields.
+    field_refs: Vec<Ref>,
+    /// The ID of the standard font used by text field appearances, if any.
+    field_font_ref: Option<Ref>,
+    /// Whether some form fields lack an appearance and the viewer has to
+    /// generate it.
+    need_appearances: bool,
     /// Handles color space writing.
     colors: ColorSpaces,
 
@@ -130,11 +144,15 @@ impl<'a> PdfContext<'a> {
             alloc,
             page_tree_ref,
             page_refs: vec![],
+            page_content_refs: vec![],
             font_refs: vec![],
             image_refs: vec![],
             gradient_refs: vec![],
             pattern_refs: vec![],
             ext_gs_refs: vec![],
+            field_refs: vec![],
+            field_font_ref: None,
+            need_appearances: false,
             colors: ColorSpaces::default(),
             font_map: Remapper::new(),
             image_map: Remapper::new(),
@@ -167,6 +185,9 @@ fn write_catalog(ctx: &mut PdfContext, ident: Option<&str>, timestamp: Option<Da
     // Write the page labels.
     let page_labels = page::write_page_labels(ctx);
 
+    // Write the named destinations.
+    let dests = write_named_destinations(ctx);
+
     // Write the document information.
     let mut info = ctx.pdf.document_info(ctx.alloc.bump());
     let mut xmp = XmpWriter::new();
@@ -268,15 +289,65 @@ fn write_catalog(ctx: &mut PdfContext, ident: Option<&str>, timestamp: Option<Da
         }
     }
 
+    // Insert the named destinations.
+    if !dests.is_empty() {
+        let mut names = catalog.names();
+        let mut tree = names.destinations();
+        let mut entries = tree.names();
+        for (label, dest_ref) in &dests {
+            entries.insert(Str(label.as_str().as_bytes()), *dest_ref);
+        }
+    }
+
     if let Some(outline_root_id) = outline_root_id {
         catalog.outlines(outline_root_id);
     }
 
+    // Insert the interactive form.
+    if !ctx.field_refs.is_empty() {
+        let mut form = catalog.insert(Name(b"AcroForm")).dict();
+        form.insert(Name(b"Fields"))
+            .array()
+            .items(ctx.field_refs.iter().copied());
+        if let Some(font_ref) = ctx.field_font_ref {
+            form.insert(Name(b"DR"))
+                .dict()
+                .insert(Name(b"Font"))
+                .dict()
+                .pair(Name(b"Helv"), font_ref);
+        }
+        if ctx.need_appearances {
+            form.pair(Name(b"NeedAppearances"), true);
+        }
+    }
+
     if let Some(lang) = lang {
         catalog.lang(TextStr(lang.as_str()));
     }
 }
 
+/// Write a destination for each labelled heading and figure, so that external
+/// links of the form `document.pdf#label` work. Returns the labels along with
+/// the references of their destinations, sorted as required by the name tree.
+fn write_named_destinations(ctx: &mut PdfContext) -> Vec<(Label, Ref)> {
+    let mut dests = vec![];
+    for (label, pos) in ctx.document.named_destinations() {
+        let index = pos.page.get() - 1;
+        let Some(page) = ctx.pages.get(index) else { continue };
+
+        let y = (pos.point.y - Abs::pt(10.0)).max(Abs::zero());
+        let dest_ref = ctx.alloc.bump();
+        ctx.pdf
+            .indirect(dest_ref)
+            .start::<Destination>()
+            .page(ctx.page_refs[index])
+            .xyz(pos.point.x.to_f32(), (page.size.y - y).to_f32(), None);
+
+        dests.push((label, dest_ref));
+    }
+    dests
+}
+
 /// Compress data with the DEFLATE algorithm.
 #[tracing::instrument(skip_all)]
 fn deflate(data: &[u8]) -> Vec<u8> {
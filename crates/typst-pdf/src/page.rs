@@ -3,19 +3,19 @@ use std::num::NonZeroUsize;
 
 use ecow::{eco_format, EcoString};
 use pdf_writer::types::{
-    ActionType, AnnotationType, ColorSpaceOperand, LineCapStyle, LineJoinStyle,
-    NumberingStyle,
+    ActionType, AnnotationFlags, AnnotationType, ColorSpaceOperand, LineCapStyle,
+    LineJoinStyle, NumberingStyle,
 };
-use pdf_writer::writers::PageLabel;
+use pdf_writer::writers::{Annotation, PageLabel};
 use pdf_writer::{Content, Filter, Finish, Name, Rect, Ref, Str, TextStr};
 use typst::introspection::Meta;
 use typst::layout::{
     Abs, Em, Frame, FrameItem, GroupItem, PdfPageLabel, PdfPageLabelStyle, Point, Ratio,
     Size, Transform,
 };
-use typst::model::Destination;
+use typst::model::{Destination, FieldElem, FieldKind};
 use typst::text::{Font, TextItem};
-use typst::util::Numeric;
+use typst::util::{Numeric, SliceExt};
 use typst::visualize::{
     FixedStroke, Geometry, Image, LineCap, LineJoin, Paint, Path, PathItem, Shape,
 };
@@ -26,11 +26,19 @@ use crate::image::deferred_image;
 use crate::{deflate_memoized, AbsExt, EmExt, PdfContext};
 
 /// Construct page objects.
+///
+/// The content stream of each page is compressed and written right away, so
+/// that only one page's uncompressed content is held in memory at a time.
+/// Resources shared between pages, like fonts and images, are written later.
 #[tracing::instrument(skip_all)]
 pub(crate) fn construct_pages(ctx: &mut PdfContext, frames: &[Frame]) {
     for frame in frames {
-        let (page_ref, page) = construct_page(ctx, frame);
+        let (page_ref, mut page) = construct_page(ctx, frame);
+        let content_ref = ctx.alloc.bump();
+        let data = deflate_memoized(&std::mem::take(&mut page.content));
+        ctx.pdf.stream(content_ref, &data).filter(Filter::FlateDecode);
         ctx.page_refs.push(page_ref);
+        ctx.page_content_refs.push(content_ref);
         ctx.pages.push(page);
     }
 }
@@ -50,6 +58,7 @@ pub(crate) fn construct_page(ctx: &mut PdfContext, frame: &Frame) -> (Ref, Page)
         saves: vec![],
         bottom: 0.0,
         links: vec![],
+        fields: vec![],
         resources: HashMap::default(),
     };
 
@@ -75,6 +84,7 @@ pub(crate) fn construct_page(ctx: &mut PdfContext, frame: &Frame) -> (Ref, Page)
         id: ctx.page_ref,
         uses_opacities: ctx.uses_opacities,
         links: ctx.links,
+        fields: ctx.fields,
         label: ctx.label,
         resources: ctx.resources,
     };
@@ -137,6 +147,11 @@ pub(crate) fn write_page_tree(ctx: &mut PdfContext) {
     resources.finish();
     pages.finish();
 
+    // Write the standard font used by the appearance streams of text fields.
+    if let Some(font_ref) = ctx.field_font_ref {
+        ctx.pdf.type1_font(font_ref).base_font(Name(b"Helvetica"));
+    }
+
     // Write all of the functions used by the document.
     ctx.colors.write_functions(&mut ctx.pdf);
 }
@@ -144,8 +159,14 @@ pub(crate) fn write_page_tree(ctx: &mut PdfContext) {
 /// Write a page tree node.
 #[tracing::instrument(skip_all)]
 fn write_page(ctx: &mut PdfContext, i: usize) {
+    // The form fields are written as separate objects after the page, so that
+    // the catalog's form can refer to them.
+    let fields = std::mem::take(&mut ctx.pages[i].fields);
+    let field_refs: Vec<Ref> = fields.iter().map(|_| ctx.alloc.bump()).collect();
+    ctx.field_refs.extend(field_refs.iter().copied());
+
     let page = &ctx.pages[i];
-    let content_id = ctx.alloc.bump();
+    let content_id = ctx.page_content_refs[i];
 
     let mut page_writer = ctx.pdf.page(page.id);
     page_writer.parent(ctx.page_tree_ref);
@@ -165,9 +186,9 @@ fn write_page(ctx: &mut PdfContext, i: usize) {
             .srgb();
     }
 
-    let mut annotations = page_writer.annotations();
+    let mut annotations = page_writer.insert(Name(b"Annots")).array();
     for (dest, rect) in &page.links {
-        let mut annotation = annotations.push();
+        let mut annotation = annotations.push().start::<Annotation>();
         annotation.subtype(AnnotationType::Link).rect(*rect);
         annotation.border(0.0, 0.0, 0.0, None);
 
@@ -195,11 +216,145 @@ fn write_page(ctx: &mut PdfContext, i: usize) {
         }
     }
 
+    annotations.items(field_refs.iter().copied());
     annotations.finish();
     page_writer.finish();
 
-    let data = deflate_memoized(&page.content);
-    ctx.pdf.stream(content_id, &data).filter(Filter::FlateDecode);
+    for ((field, rect), &id) in fields.iter().zip(&field_refs) {
+        write_field(ctx, i, id, field, *rect);
+    }
+}
+
+/// Write a form field together with its widget annotation and appearance.
+///
+/// The field and the widget share one dictionary, so the field's reference is
+/// both listed in the page's annotations and in the document's form.
+fn write_field(ctx: &mut PdfContext, i: usize, id: Ref, elem: &FieldElem, rect: Rect) {
+    let width = (rect.x2 - rect.x1).abs();
+    let height = (rect.y2 - rect.y1).abs();
+    let bbox = Rect::new(0.0, 0.0, width, height);
+
+    // Write the appearance streams first, as the field's dictionary refers to
+    // them. Checkboxes have one appearance for each state.
+    let (normal, off) = match elem.kind() {
+        FieldKind::Text => {
+            let id = ctx.alloc.bump();
+            write_text_appearance(ctx, id, bbox, elem.initial_value());
+            (id, None)
+        }
+        FieldKind::Checkbox => {
+            let on = ctx.alloc.bump();
+            let off = ctx.alloc.bump();
+            write_checkbox_appearance(ctx, on, bbox, true);
+            write_checkbox_appearance(ctx, off, bbox, false);
+            (on, Some(off))
+        }
+        FieldKind::Signature => {
+            let id = ctx.alloc.bump();
+            ctx.pdf.form_xobject(id, &[]).bbox(bbox);
+            (id, None)
+        }
+    };
+
+    let mut field = ctx.pdf.indirect(id).dict();
+    field.pair(Name(b"Type"), Name(b"Annot"));
+    field.pair(Name(b"Subtype"), Name(b"Widget"));
+    field.pair(Name(b"Rect"), rect);
+    field.pair(Name(b"P"), ctx.page_refs[i]);
+    field.pair(Name(b"F"), AnnotationFlags::PRINT.bits() as i32);
+    field.pair(Name(b"T"), TextStr(elem.name()));
+
+    match elem.kind() {
+        FieldKind::Text => {
+            let value = elem.initial_value();
+            field.pair(Name(b"FT"), Name(b"Tx"));
+            field.pair(Name(b"V"), TextStr(value));
+            field.pair(Name(b"DV"), TextStr(value));
+            field.pair(Name(b"DA"), Str(FIELD_DEFAULT_APPEARANCE));
+        }
+        FieldKind::Checkbox => {
+            let state = if *elem.initial_checked() { Name(b"Yes") } else { Name(b"Off") };
+            field.pair(Name(b"FT"), Name(b"Btn"));
+            field.pair(Name(b"V"), state);
+            field.pair(Name(b"DV"), state);
+            field.pair(Name(b"AS"), state);
+        }
+        FieldKind::Signature => {
+            field.pair(Name(b"FT"), Name(b"Sig"));
+        }
+    }
+
+    let mut appearance = field.insert(Name(b"AP")).dict();
+    match off {
+        Some(off) => {
+            appearance
+                .insert(Name(b"N"))
+                .dict()
+                .pair(Name(b"Yes"), normal)
+                .pair(Name(b"Off"), off);
+        }
+        None => {
+            appearance.pair(Name(b"N"), normal);
+        }
+    }
+}
+
+/// The default appearance of text fields, referring to the font in the form's
+/// resources.
+const FIELD_DEFAULT_APPEARANCE: &[u8] = b"/Helv 0 Tf 0 g";
+
+/// Write the appearance stream of a text field showing its initial value.
+///
+/// The value is shown in the standard Helvetica font, which we can only use
+/// for printable ASCII. For other values, we leave the appearance empty and
+/// ask the viewer to generate it instead.
+fn write_text_appearance(ctx: &mut PdfContext, id: Ref, bbox: Rect, value: &str) {
+    let font = *ctx.field_font_ref.get_or_insert_with(|| ctx.alloc.bump());
+    let height = bbox.y2 - bbox.y1;
+    let size = (height * 0.7).min(12.0);
+
+    let mut content = Content::new();
+    content.begin_marked_content(Name(b"Tx"));
+    if value.chars().all(|c| matches!(c, ' '..='~')) {
+        content.save_state();
+        content.begin_text();
+        content.set_font(Name(b"Helv"), size);
+        content.set_fill_gray(0.0);
+        content.next_line(2.0, (height - size) / 2.0 + size * 0.22);
+        content.show(Str(value.as_bytes()));
+        content.end_text();
+        content.restore_state();
+    } else {
+        ctx.need_appearances = true;
+    }
+    content.end_marked_content();
+
+    let data = content.finish();
+    let mut form = ctx.pdf.form_xobject(id, &data);
+    form.bbox(bbox);
+    form.resources().fonts().pair(Name(b"Helv"), font);
+}
+
+/// Write the appearance stream of a checkbox in its checked or unchecked
+/// state.
+fn write_checkbox_appearance(ctx: &mut PdfContext, id: Ref, bbox: Rect, checked: bool) {
+    let mut content = Content::new();
+    if checked {
+        // Draw a check mark.
+        let w = bbox.x2 - bbox.x1;
+        let h = bbox.y2 - bbox.y1;
+        content.save_state();
+        content.set_stroke_gray(0.0);
+        content.set_line_width(w.min(h) * 0.12);
+        content.move_to(w * 0.2, h * 0.5);
+        content.line_to(w * 0.42, h * 0.25);
+        content.line_to(w * 0.8, h * 0.78);
+        content.stroke();
+        content.restore_state();
+    }
+
+    let data = content.finish();
+    ctx.pdf.form_xobject(id, &data).bbox(bbox);
 }
 
 /// Write the page labels.
@@ -257,12 +412,15 @@ pub struct Page {
     pub id: Ref,
     /// The page's dimensions.
     pub size: Size,
-    /// The page's content stream.
+    /// The page's content stream. Empty for document pages once their
+    /// content has been written.
     pub content: Vec<u8>,
     /// Whether the page uses opacities.
     pub uses_opacities: bool,
     /// Links in the PDF coordinate system.
     pub links: Vec<(Destination, Rect)>,
+    /// Form fields in the PDF coordinate system.
+    pub fields: Vec<(FieldElem, Rect)>,
     /// The page's PDF label.
     pub label: Option<PdfPageLabel>,
     /// The page's used resources
@@ -335,6 +493,7 @@ pub struct PageContext<'a, 'b> {
     bottom: f32,
     uses_opacities: bool,
     links: Vec<(Destination, Rect)>,
+    fields: Vec<(FieldElem, Rect)>,
     /// Keep track of the resources being used in the page.
     pub resources: HashMap<PageResource, usize>,
 }
@@ -570,7 +729,12 @@ fn write_frame(ctx: &mut PageContext, frame: &Frame) {
             FrameItem::Image(image, size, _) => write_image(ctx, x, y, image, *size),
             FrameItem::Meta(meta, size) => match meta {
                 Meta::Link(dest) => write_link(ctx, pos, dest, *size),
-                Meta::Elem(_) => {}
+                Meta::Elem(elem) => {
+                    if let Some(field) = elem.to::<FieldElem>() {
+                        let rect = bounding_rect(ctx, pos, *size);
+                        ctx.fields.push((field.clone(), rect));
+                    }
+                }
                 Meta::Hide => {}
                 Meta::PageNumbering(_) => {}
                 Meta::PdfPageLabel(label) => ctx.label = Some(label.clone()),
@@ -628,42 +792,59 @@ fn write_text(ctx: &mut PageContext, pos: Point, text: &TextItem) {
     // Positiosn the text.
     ctx.content.set_text_matrix([1.0, 0.0, 0.0, -1.0, x, y]);
 
-    let mut positioned = ctx.content.show_positioned();
-    let mut items = positioned.items();
     let mut adjustment = Em::zero();
+    let mut rise = Em::zero();
     let mut encoded = vec![];
 
-    // Write the glyphs with kerning adjustments.
-    for glyph in &text.glyphs {
-        adjustment += glyph.x_offset;
+    // Write the glyphs with kerning adjustments. Runs of glyphs with a vertical
+    // offset are moved with the text rise.
+    for (y_offset, run) in text.glyphs.group_by_key(|g| g.y_offset) {
+        if y_offset != rise {
+            ctx.content.set_rise(y_offset.at(text.size).to_f32());
+            rise = y_offset;
+        }
+
+        let mut positioned = ctx.content.show_positioned();
+        let mut items = positioned.items();
+
+        for glyph in run {
+            adjustment += glyph.x_offset;
+
+            if !adjustment.is_zero() {
+                if !encoded.is_empty() {
+                    items.show(Str(&encoded));
+                    encoded.clear();
+                }
 
-        if !adjustment.is_zero() {
-            if !encoded.is_empty() {
-                items.show(Str(&encoded));
-                encoded.clear();
+                items.adjust(-adjustment.to_font_units());
+                adjustment = Em::zero();
             }
 
-            items.adjust(-adjustment.to_font_units());
-            adjustment = Em::zero();
-        }
+            let cid = crate::font::glyph_cid(&text.font, glyph.id);
+            encoded.push((cid >> 8) as u8);
+            encoded.push((cid & 0xff) as u8);
 
-        let cid = crate::font::glyph_cid(&text.font, glyph.id);
-        encoded.push((cid >> 8) as u8);
-        encoded.push((cid & 0xff) as u8);
+            if let Some(advance) = text.font.advance(glyph.id) {
+                adjustment += glyph.x_advance - advance;
+            }
+
+            adjustment -= glyph.x_offset;
+        }
 
-        if let Some(advance) = text.font.advance(glyph.id) {
-            adjustment += glyph.x_advance - advance;
+        if !encoded.is_empty() {
+            items.show(Str(&encoded));
+            encoded.clear();
         }
 
-        adjustment -= glyph.x_offset;
+        items.finish();
+        positioned.finish();
     }
 
-    if !encoded.is_empty() {
-        items.show(Str(&encoded));
+    // The text rise is part of the graphics state, so we need to reset it.
+    if !rise.is_zero() {
+        ctx.content.set_rise(0.0);
     }
 
-    items.finish();
-    positioned.finish();
     ctx.content.end_text();
 }
 
@@ -746,11 +927,14 @@ fn write_path(ctx: &mut PageContext, x: f32, y: f32, path: &Path) {
 
 /// Encode a vector or raster image into the content stream.
 fn write_image(ctx: &mut PageContext, x: f32, y: f32, image: &Image, size: Size) {
-    let index = ctx.parent.image_map.insert(image.clone());
+    // The alternative text is written into the content stream, so images that
+    // only differ in it can share one encoded XObject.
+    let shared = image.without_alt();
+    let index = ctx.parent.image_map.insert(shared.clone());
     ctx.parent
         .image_deferred_map
         .entry(index)
-        .or_insert_with(|| deferred_image(image.clone()));
+        .or_insert_with(|| deferred_image(shared));
 
     let name = eco_format!("Im{index}");
     let w = size.x.to_f32();
@@ -779,12 +963,18 @@ fn write_image(ctx: &mut PageContext, x: f32, y: f32, image: &Image, size: Size)
 
 /// Save a link for later writing in the annotations dictionary.
 fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size) {
+    let rect = bounding_rect(ctx, pos, size);
+    ctx.links.push((dest.clone(), rect));
+}
+
+/// Compute the bounding box of a transformed area in the PDF coordinate
+/// system.
+fn bounding_rect(ctx: &PageContext, pos: Point, size: Size) -> Rect {
     let mut min_x = Abs::inf();
     let mut min_y = Abs::inf();
     let mut max_x = -Abs::inf();
     let mut max_y = -Abs::inf();
 
-    // Compute the bounding box of the transformed link.
     for point in [
         pos,
         pos + Point::with_x(size.x),
@@ -802,9 +992,7 @@ fn write_link(ctx: &mut PageContext, pos: Point, dest: &Destination, size: Size)
     let x2 = max_x.to_f32();
     let y1 = max_y.to_f32();
     let y2 = min_y.to_f32();
-    let rect = Rect::new(x1, y1, x2, y2);
-
-    ctx.links.push((dest.clone(), rect));
+    Rect::new(x1, y1, x2, y2)
 }
 
 fn to_pdf_line_cap(cap: LineCap) -> LineCapStyle {
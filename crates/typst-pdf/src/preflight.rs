@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use ecow::EcoVec;
+use ttf_parser::Permissions;
+use typst::diag::{warning, SourceDiagnostic};
+use typst::foundations::Repr;
+use typst::layout::{Abs, Frame, FrameItem, Transform};
+use typst::model::Document;
+use typst::syntax::Span;
+use typst::text::Font;
+use typst::util::hash128;
+use typst::visualize::{ColorSpace, Image, ImageKind, Paint};
+
+/// Thresholds and intents for the [`preflight`] checks.
+#[derive(Debug, Copy, Clone)]
+pub struct PreflightOptions {
+    /// Whether the document is meant to be printed in CMYK. If so, colors in
+    /// other color spaces (except for grayscale) are reported.
+    pub cmyk: bool,
+    /// Strokes that are thinner than this at their rendered size are
+    /// reported as hairlines.
+    pub min_stroke: Abs,
+    /// Raster images with a lower resolution at their rendered size are
+    /// reported.
+    pub min_dpi: f64,
+}
+
+impl Default for PreflightOptions {
+    fn default() -> Self {
+        Self {
+            cmyk: false,
+            min_stroke: Abs::pt(0.25),
+            min_dpi: 150.0,
+        }
+    }
+}
+
+/// Check a document for common problems when printing it.
+///
+/// Reports colors outside of the CMYK color space for CMYK documents, hairline
+/// strokes, images with a low resolution at their rendered size and fonts
+/// whose license does not permit embedding them. The checks don't affect the
+/// PDF export, they only produce warnings.
+#[tracing::instrument(skip_all)]
+pub fn preflight(
+    document: &Document,
+    options: &PreflightOptions,
+) -> EcoVec<SourceDiagnostic> {
+    let mut checker = Checker {
+        options,
+        warnings: EcoVec::new(),
+        seen: HashSet::new(),
+    };
+    for page in &document.pages {
+        checker.frame(page, Transform::identity());
+    }
+    checker.warnings
+}
+
+/// Collects preflight warnings while walking the frames.
+struct Checker<'a> {
+    options: &'a PreflightOptions,
+    warnings: EcoVec<SourceDiagnostic>,
+    seen: HashSet<u128>,
+}
+
+impl Checker<'_> {
+    /// Check the items of a frame.
+    fn frame(&mut self, frame: &Frame, ts: Transform) {
+        for (pos, item) in frame.items() {
+            let ts = ts.pre_concat(Transform::translate(pos.x, pos.y));
+            match item {
+                FrameItem::Group(group) => {
+                    self.frame(&group.frame, ts.pre_concat(group.transform));
+                }
+                FrameItem::Text(text) => {
+                    let span = text.glyphs.first().map_or(Span::detached(), |g| g.span.0);
+                    self.paint(&text.fill, span);
+                    self.font(&text.font, span);
+                }
+                FrameItem::Shape(shape, span) => {
+                    if let Some(fill) = &shape.fill {
+                        self.paint(fill, *span);
+                    }
+                    if let Some(stroke) = &shape.stroke {
+                        self.paint(&stroke.paint, *span);
+                        let (sx, sy) = scale(ts);
+                        let thickness = stroke.thickness * sx.min(sy);
+                        if thickness > Abs::zero() && thickness < self.options.min_stroke
+                        {
+                            self.warn(warning!(
+                                *span,
+                                "stroke is only {} thick",
+                                thickness.repr();
+                                hint: "strokes thinner than {} may not be visible in print",
+                                self.options.min_stroke.repr()
+                            ));
+                        }
+                    }
+                }
+                FrameItem::Image(image, size, span) => {
+                    let (sx, sy) = scale(ts);
+                    self.image(image, size.x * sx, size.y * sy, *span);
+                }
+                FrameItem::Meta(..) => {}
+            }
+        }
+    }
+
+    /// Check that a paint fits the color intent.
+    fn paint(&mut self, paint: &Paint, span: Span) {
+        if !self.options.cmyk {
+            return;
+        }
+
+        let Paint::Solid(color) = paint else { return };
+        if !matches!(color.space(), ColorSpace::Cmyk | ColorSpace::D65Gray) {
+            self.warn(warning!(
+                span,
+                "{} is not a CMYK color",
+                color.repr();
+                hint: "the document is checked for CMYK printing"
+            ));
+        }
+    }
+
+    /// Check that a font may be embedded.
+    fn font(&mut self, font: &Font, span: Span) {
+        if font.ttf().permissions() == Some(Permissions::Restricted) {
+            self.warn(warning!(
+                span,
+                "the license of font {} does not permit embedding it",
+                font.info().family
+            ));
+        }
+    }
+
+    /// Check the resolution of an image at its rendered size.
+    fn image(&mut self, image: &Image, width: Abs, height: Abs, span: Span) {
+        let ImageKind::Raster(_) = image.kind() else { return };
+        if width <= Abs::zero() || height <= Abs::zero() {
+            return;
+        }
+
+        let dpi = (image.width() as f64 / width.to_inches())
+            .min(image.height() as f64 / height.to_inches());
+        if dpi < self.options.min_dpi {
+            self.warn(warning!(
+                span,
+                "image has a resolution of only {} dpi at its rendered size",
+                dpi.round();
+                hint: "images should have at least {} dpi for print",
+                self.options.min_dpi
+            ));
+        }
+    }
+
+    /// Add a warning unless the same one was already reported.
+    fn warn(&mut self, warning: SourceDiagnostic) {
+        if self.seen.insert(hash128(&(&warning.span, &warning.message))) {
+            self.warnings.push(warning);
+        }
+    }
+}
+
+/// The horizontal and vertical scale factors of a transform.
+fn scale(ts: Transform) -> (f64, f64) {
+    (ts.sx.get().hypot(ts.ky.get()), ts.kx.get().hypot(ts.sy.get()))
+}
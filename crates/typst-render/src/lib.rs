@@ -236,7 +236,8 @@ fn render_text(canvas: &mut sk::Pixmap, state: State, text: &TextItem) {
     for glyph in &text.glyphs {
         let id = GlyphId(glyph.id);
         let offset = x + glyph.x_offset.at(text.size).to_f32();
-        let state = state.pre_translate(Point::new(Abs::raw(offset as _), Abs::raw(0.0)));
+        let rise = glyph.y_offset.at(text.size);
+        let state = state.pre_translate(Point::new(Abs::raw(offset as _), -rise));
 
         render_svg_glyph(canvas, state, text, id)
             .or_else(|| render_bitmap_glyph(canvas, state, text, id))
@@ -378,11 +379,7 @@ fn render_outline_glyph(
     // rasterization can't be used due to very large text size or weird
     // scale/skewing transforms.
     if ppem > 100.0 || ts.kx != 0.0 || ts.ky != 0.0 || ts.sx != ts.sy {
-        let path = {
-            let mut builder = WrappedPathBuilder(sk::PathBuilder::new());
-            text.font.ttf().outline_glyph(id, &mut builder)?;
-            builder.0.finish()?
-        };
+        let path = glyph_path(&text.font, id)?;
 
         let scale = text.size.to_f32() / text.font.units_per_em() as f32;
 
@@ -415,7 +412,7 @@ fn render_outline_glyph(
         y: u32,
         size: u32,
     ) -> Option<Arc<Bitmap>> {
-        let glyph = pixglyph::Glyph::load(font.ttf(), id)?;
+        let glyph = glyph_outline(font, id)?;
         Some(Arc::new(glyph.rasterize(
             f32::from_bits(x),
             f32::from_bits(y),
@@ -450,6 +447,25 @@ fn render_outline_glyph(
     Some(())
 }
 
+/// Extract a glyph's outline as a path in font units.
+///
+/// This is cached because the same glyphs are drawn over and over again.
+#[comemo::memoize]
+fn glyph_path(font: &Font, id: GlyphId) -> Option<Arc<sk::Path>> {
+    let mut builder = WrappedPathBuilder(sk::PathBuilder::new());
+    font.ttf().outline_glyph(id, &mut builder)?;
+    builder.0.finish().map(Arc::new)
+}
+
+/// Load a glyph's outline for rasterization with `pixglyph`.
+///
+/// The rasterized bitmaps depend on the glyph's exact position, so this caches
+/// the outline separately to avoid extracting it again for each occurrence.
+#[comemo::memoize]
+fn glyph_outline(font: &Font, id: GlyphId) -> Option<Arc<pixglyph::Glyph>> {
+    pixglyph::Glyph::load(font.ttf(), id).map(Arc::new)
+}
+
 fn write_bitmap<S: PaintSampler>(
     canvas: &mut sk::Pixmap,
     bitmap: &Bitmap,
@@ -699,7 +715,7 @@ fn render_image(
     let w = (scale_x * view_width.max(aspect * view_height)).ceil() as u32;
     let h = ((w as f32) / aspect).ceil() as u32;
 
-    let pixmap = scaled_texture(image, w, h)?;
+    let pixmap = scaled_texture(&image.without_alt(), w, h)?;
     let paint_scale_x = view_width / pixmap.width() as f32;
     let paint_scale_y = view_height / pixmap.height() as f32;
 
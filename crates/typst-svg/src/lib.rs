@@ -336,17 +336,19 @@ impl SVGRenderer {
         for glyph in &text.glyphs {
             let id = GlyphId(glyph.id);
             let offset = x + glyph.x_offset.at(text.size).to_pt();
+            let rise = glyph.y_offset.at(text.size).to_pt();
 
-            self.render_svg_glyph(text, id, offset, scale)
-                .or_else(|| self.render_bitmap_glyph(text, id, offset))
+            self.render_svg_glyph(text, id, offset, rise, scale)
+                .or_else(|| self.render_bitmap_glyph(text, id, offset, rise))
                 .or_else(|| {
                     self.render_outline_glyph(
                         state
                             .pre_concat(Transform::scale(Ratio::one(), -Ratio::one()))
-                            .pre_translate(Point::new(Abs::pt(offset), Abs::zero())),
+                            .pre_translate(Point::new(Abs::pt(offset), Abs::pt(rise))),
                         text,
                         id,
                         offset,
+                        rise,
                         scale,
                     )
                 });
@@ -363,6 +365,7 @@ impl SVGRenderer {
         text: &TextItem,
         id: GlyphId,
         x_offset: f64,
+        y_offset: f64,
         scale: f64,
     ) -> Option<()> {
         let data_url = convert_svg_glyph_to_base64_url(&text.font, id)?;
@@ -381,6 +384,9 @@ impl SVGRenderer {
         self.xml.start_element("use");
         self.xml.write_attribute_fmt("xlink:href", format_args!("#{id}"));
         self.xml.write_attribute("x", &x_offset);
+        if y_offset != 0.0 {
+            self.xml.write_attribute("y", &y_offset);
+        }
         self.xml.end_element();
 
         Some(())
@@ -392,6 +398,7 @@ impl SVGRenderer {
         text: &TextItem,
         id: GlyphId,
         x_offset: f64,
+        y_offset: f64,
     ) -> Option<()> {
         let (image, bitmap_x_offset, bitmap_y_offset) =
             convert_bitmap_glyph_to_image(&text.font, id)?;
@@ -417,6 +424,9 @@ impl SVGRenderer {
         // it.
         let scale_factor = target_height / image.height() as f64;
         self.xml.write_attribute("x", &(x_offset / scale_factor));
+        if y_offset != 0.0 {
+            self.xml.write_attribute("y", &(-y_offset / scale_factor));
+        }
         self.xml.write_attribute_fmt(
             "transform",
             format_args!("scale({scale_factor} -{scale_factor})",),
@@ -433,6 +443,7 @@ impl SVGRenderer {
         text: &TextItem,
         glyph_id: GlyphId,
         x_offset: f64,
+        y_offset: f64,
         scale: f64,
     ) -> Option<()> {
         let scale = Ratio::new(scale);
@@ -447,6 +458,9 @@ impl SVGRenderer {
         self.xml.start_element("use");
         self.xml.write_attribute_fmt("xlink:href", format_args!("#{id}"));
         self.xml.write_attribute_fmt("x", format_args!("{x_offset}"));
+        if y_offset != 0.0 {
+            self.xml.write_attribute_fmt("y", format_args!("{y_offset}"));
+        }
         self.write_fill(
             &text.fill,
             Size::new(Abs::pt(width), Abs::pt(height)),
@@ -1261,6 +1261,11 @@ impl<'a> Named<'a> {
     pub fn expr_ident(self) -> Option<Ident<'a>> {
         self.0.cast_last_match()
     }
+
+    /// The right-hand side of the pair as a pattern.
+    pub fn pattern(self) -> Pattern<'a> {
+        self.0.cast_last_match().unwrap_or_default()
+    }
 }
 
 node! {
@@ -1675,6 +1680,8 @@ node! {
 pub enum Param<'a> {
     /// A positional parameter: `x`.
     Pos(Pattern<'a>),
+    /// A positional parameter with a default value: `y = 2`.
+    Default(DefaultParam<'a>),
     /// A named parameter with a default value: `draw: false`.
     Named(Named<'a>),
     /// An argument sink: `..args`.
@@ -1684,6 +1691,7 @@ pub enum Param<'a> {
 impl<'a> AstNode<'a> for Param<'a> {
     fn from_untyped(node: &'a SyntaxNode) -> Option<Self> {
         match node.kind() {
+            SyntaxKind::DefaultParam => node.cast().map(Self::Default),
             SyntaxKind::Named => node.cast().map(Self::Named),
             SyntaxKind::Spread => node.cast().map(Self::Sink),
             _ => node.cast().map(Self::Pos),
@@ -1693,12 +1701,30 @@ impl<'a> AstNode<'a> for Param<'a> {
     fn to_untyped(self) -> &'a SyntaxNode {
         match self {
             Self::Pos(v) => v.to_untyped(),
+            Self::Default(v) => v.to_untyped(),
             Self::Named(v) => v.to_untyped(),
             Self::Sink(v) => v.to_untyped(),
         }
     }
 }
 
+node! {
+    /// A positional parameter with a default value: `y = 2`.
+    DefaultParam
+}
+
+impl<'a> DefaultParam<'a> {
+    /// The name of the parameter: `y`.
+    pub fn name(self) -> Ident<'a> {
+        self.0.cast_first_match().unwrap_or_default()
+    }
+
+    /// The default value: `2`.
+    pub fn expr(self) -> Expr<'a> {
+        self.0.cast_last_match().unwrap_or_default()
+    }
+}
+
 node! {
     /// A destructuring pattern: `x` or `(x, _, ..y)`.
     Destructuring
@@ -1710,14 +1736,18 @@ impl<'a> Destructuring<'a> {
         self.0.children().filter_map(SyntaxNode::cast)
     }
 
-    /// Returns a list of all identifiers in the pattern.
-    pub fn idents(self) -> impl DoubleEndedIterator<Item = Ident<'a>> {
-        self.bindings().filter_map(|binding| match binding {
-            DestructuringKind::Normal(Expr::Ident(ident)) => Some(ident),
-            DestructuringKind::Sink(spread) => spread.name(),
-            DestructuringKind::Named(named) => named.expr_ident(),
-            _ => Option::None,
-        })
+    /// Returns a list of all identifiers in the pattern, including those in
+    /// nested patterns.
+    pub fn idents(self) -> Vec<Ident<'a>> {
+        self.bindings()
+            .flat_map(|binding| match binding {
+                DestructuringKind::Normal(Expr::Ident(ident)) => vec![ident],
+                DestructuringKind::Sink(spread) => spread.name().into_iter().collect(),
+                DestructuringKind::Named(named) => named.pattern().idents(),
+                DestructuringKind::Nested(nested) => nested.idents(),
+                _ => vec![],
+            })
+            .collect()
     }
 }
 
@@ -1732,11 +1762,14 @@ pub enum DestructuringKind<'a> {
     Named(Named<'a>),
     /// A placeholder: `_`.
     Placeholder(Underscore<'a>),
+    /// A nested destructuring pattern: `(x, y)`.
+    Nested(Destructuring<'a>),
 }
 
 impl<'a> AstNode<'a> for DestructuringKind<'a> {
     fn from_untyped(node: &'a SyntaxNode) -> Option<Self> {
         match node.kind() {
+            SyntaxKind::Destructuring => node.cast().map(Self::Nested),
             SyntaxKind::Named => node.cast().map(Self::Named),
             SyntaxKind::Spread => node.cast().map(Self::Sink),
             SyntaxKind::Underscore => node.cast().map(Self::Placeholder),
@@ -1750,6 +1783,7 @@ impl<'a> AstNode<'a> for DestructuringKind<'a> {
             Self::Named(v) => v.to_untyped(),
             Self::Sink(v) => v.to_untyped(),
             Self::Placeholder(v) => v.to_untyped(),
+            Self::Nested(v) => v.to_untyped(),
         }
     }
 }
@@ -1788,7 +1822,7 @@ impl<'a> Pattern<'a> {
     pub fn idents(self) -> Vec<Ident<'a>> {
         match self {
             Pattern::Normal(Expr::Ident(ident)) => vec![ident],
-            Pattern::Destructuring(destruct) => destruct.idents().collect(),
+            Pattern::Destructuring(destruct) => destruct.idents(),
             _ => vec![],
         }
     }
@@ -40,6 +40,11 @@ fn markup(
     mut stop: impl FnMut(&Parser) -> bool,
 ) {
     let m = p.marker();
+    if !p.descend() {
+        p.wrap(m, SyntaxKind::Markup);
+        return;
+    }
+
     let mut nesting: usize = 0;
     while !p.eof() {
         match p.current() {
@@ -64,6 +69,7 @@ fn markup(
             p.unexpected();
         }
     }
+    p.ascend();
     p.wrap(m, SyntaxKind::Markup);
 }
 
@@ -251,6 +257,10 @@ fn math_expr(p: &mut Parser) {
 }
 
 fn math_expr_prec(p: &mut Parser, min_prec: usize, stop: SyntaxKind) {
+    if !p.descend() {
+        return;
+    }
+
     let m = p.marker();
     let mut continuable = false;
     match p.current() {
@@ -395,6 +405,8 @@ fn math_expr_prec(p: &mut Parser, min_prec: usize, stop: SyntaxKind) {
 
         p.wrap(m, kind);
     }
+
+    p.ascend();
 }
 
 fn maybe_delimited(p: &mut Parser) -> bool {
@@ -633,6 +645,10 @@ fn code_expr_prec(
     min_prec: usize,
     allow_destructuring: bool,
 ) {
+    if !p.descend() {
+        return;
+    }
+
     let m = p.marker();
     if let (false, Some(op)) = (atomic, ast::UnOp::from_kind(p.current())) {
         p.eat();
@@ -694,6 +710,8 @@ fn code_expr_prec(
 
         break;
     }
+
+    p.ascend();
 }
 
 fn code_primary(p: &mut Parser, atomic: bool, allow_destructuring: bool) {
@@ -1287,6 +1305,21 @@ fn validate_params_at(p: &mut Parser, m: Marker) {
                     child.make_erroneous();
                 }
             }
+            SyntaxKind::Binary
+                if child.children().next().map(SyntaxNode::kind)
+                    == Some(SyntaxKind::Ident)
+                    && child.children().any(|c| c.kind() == SyntaxKind::Eq) =>
+            {
+                let Some(within) = child.children_mut().first_mut() else { return };
+                if !used.insert(within.text().clone()) {
+                    within.convert_to_error(eco_format!(
+                        "duplicate parameter: {}",
+                        within.text()
+                    ));
+                    child.make_erroneous();
+                }
+                child.convert_to_kind(SyntaxKind::DefaultParam);
+            }
             SyntaxKind::Spread => {
                 let Some(within) = child.children_mut().last_mut() else { continue };
                 if used_spread {
@@ -1403,19 +1436,36 @@ fn validate_pattern<'a>(
                     child.make_erroneous();
                 }
 
-                if forbid_expressions {
-                    let Some(within) = child.children_mut().last_mut() else { return };
-                    if within.kind() != SyntaxKind::Ident
-                        && within.kind() != SyntaxKind::Underscore
-                    {
-                        within.convert_to_error(eco_format!(
-                            "expected identifier, found {}",
-                            within.kind().name(),
-                        ));
-                        child.make_erroneous();
-                    }
+                let Some(within) = child.children_mut().last_mut() else { return };
+                if matches!(
+                    within.kind(),
+                    SyntaxKind::Array | SyntaxKind::Dict | SyntaxKind::Destructuring
+                ) {
+                    validate_pattern(
+                        within.children_mut().iter_mut(),
+                        used,
+                        forbid_expressions,
+                    );
+                    within.convert_to_kind(SyntaxKind::Destructuring);
+                } else if forbid_expressions
+                    && within.kind() != SyntaxKind::Ident
+                    && within.kind() != SyntaxKind::Underscore
+                {
+                    within.convert_to_error(eco_format!(
+                        "expected identifier, found {}",
+                        within.kind().name(),
+                    ));
+                    child.make_erroneous();
                 }
             }
+            SyntaxKind::Array | SyntaxKind::Dict | SyntaxKind::Destructuring => {
+                validate_pattern(
+                    child.children_mut().iter_mut(),
+                    used,
+                    forbid_expressions,
+                );
+                child.convert_to_kind(SyntaxKind::Destructuring);
+            }
             SyntaxKind::LeftParen
             | SyntaxKind::RightParen
             | SyntaxKind::Comma
@@ -1432,6 +1482,11 @@ fn validate_pattern<'a>(
     }
 }
 
+/// The maximum nesting depth of expressions and markup. Deeper nesting is
+/// reported as an error instead of risking a stack overflow in the parser and
+/// the evaluator.
+const MAX_DEPTH: usize = 256;
+
 /// Manages parsing of a stream of tokens.
 struct Parser<'s> {
     text: &'s str,
@@ -1443,6 +1498,7 @@ struct Parser<'s> {
     nodes: Vec<SyntaxNode>,
     newline_modes: Vec<NewlineMode>,
     balanced: bool,
+    depth: usize,
 }
 
 /// How to proceed with parsing when seeing a newline.
@@ -1473,6 +1529,7 @@ impl<'s> Parser<'s> {
             nodes: vec![],
             newline_modes: vec![],
             balanced: true,
+            depth: 0,
         }
     }
 
@@ -1606,6 +1663,32 @@ impl<'s> Parser<'s> {
         }
     }
 
+    /// Descend into a nested expression or markup. Produces an error and
+    /// returns `false` if the maximum nesting depth is exceeded.
+    fn descend(&mut self) -> bool {
+        if self.depth < MAX_DEPTH {
+            self.depth += 1;
+            return true;
+        }
+
+        // Incremental reparsing starts at depth zero, so it must not accept
+        // a result that differs from a full parse.
+        self.balanced = false;
+        let message = "maximum nesting depth exceeded";
+        if !self.eof() {
+            self.trim_errors();
+            self.convert_to_error(message.into());
+        } else if !self.after_error() {
+            self.nodes.push(SyntaxNode::error(message, ""));
+        }
+        false
+    }
+
+    /// Ascend from a nested expression or markup.
+    fn ascend(&mut self) {
+        self.depth -= 1;
+    }
+
     fn enter_newline_mode(&mut self, stop: NewlineMode) {
         self.newline_modes.push(stop);
     }
@@ -1786,3 +1869,38 @@ impl<'s> Parser<'s> {
         self.nodes.drain(start..end);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn test(text: &str, nested: bool) {
+        let errors = parse(text).errors();
+        let exceeded =
+            errors.iter().any(|e| e.message == "maximum nesting depth exceeded");
+        assert_eq!(exceeded, nested, "{errors:?}");
+    }
+
+    #[test]
+    fn test_nesting_depth() {
+        let nest = |open: &str, close: &str, n: usize| {
+            format!("{}{}", open.repeat(n), close.repeat(n))
+        };
+
+        let deep = MAX_DEPTH * 8;
+        let shallow = MAX_DEPTH / 4;
+        test(&nest("*_", "_*", deep), true);
+        test(&nest("#[", "]", deep), true);
+        test(&format!("#{}", nest("{", "}", deep)), true);
+        test(&format!("#{}", nest("(", ")", deep)), true);
+        test(&format!("#({}1)", "-".repeat(deep)), true);
+        test(&format!("${}$", nest("(", ")", deep)), true);
+        test(&nest("#[", "]", shallow), false);
+
+        // Chains are parsed in a loop and don't nest the parser.
+        test(&format!("#(1{})", " + 1".repeat(deep)), false);
+        test(&format!("#f{}", "()".repeat(deep)), false);
+        test(&format!("#a{}", ".b".repeat(deep)), false);
+    }
+}
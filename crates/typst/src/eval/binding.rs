@@ -1,23 +1,30 @@
 use std::collections::HashSet;
 
-use crate::diag::{bail, At, SourceResult};
-use crate::eval::{Access, Eval, Vm};
+use crate::diag::{bail, warning, At, SourceResult};
+use crate::eval::{eval_closure, recursive_binding, Access, Eval, Vm};
 use crate::foundations::{Array, Dict, Value};
 use crate::syntax::ast::{self, AstNode};
+use crate::syntax::SyntaxNode;
 
 impl Eval for ast::LetBinding<'_> {
     type Output = Value;
 
     #[tracing::instrument(name = "LetBinding::eval", skip_all)]
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        let value = match self.init() {
-            Some(expr) => expr.eval(vm)?,
-            None => Value::None,
+        let defined = |name: &str| vm.scopes.get(name).is_ok();
+        let value = match (recursive_binding(self, defined), self.init()) {
+            (Some((closure, ident)), _) => eval_closure(vm, closure, Some(ident))?,
+            (None, Some(expr)) => expr.eval(vm)?,
+            (None, None) => Value::None,
         };
         if vm.flow.is_some() {
             return Ok(Value::None);
         }
 
+        for ident in self.kind().idents() {
+            check_shadowing(vm, ident, self.init());
+        }
+
         match self.kind() {
             ast::LetBindingKind::Normal(pattern) => destructure(vm, pattern, value)?,
             ast::LetBindingKind::Closure(ident) => vm.define(ident, value),
@@ -32,7 +39,7 @@ impl Eval for ast::DestructAssignment<'_> {
 
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
         let value = self.value().eval(vm)?;
-        destructure_impl(vm, self.pattern(), value, |vm, expr, value| {
+        destructure_impl(vm, self.pattern(), value, &|vm, expr, value| {
             let location = expr.access(vm)?;
             *location = value;
             Ok(())
@@ -41,18 +48,79 @@ impl Eval for ast::DestructAssignment<'_> {
     }
 }
 
+/// Warns when a top-level binding shadows a constant of the standard library.
+///
+/// Shadowing is considered intentional if the new definition refers to the
+/// original binding, as in `let text = text.with(red)`. Binding the original to
+/// its own name with `let text = text` thus opts out of the check for the rest
+/// of the file. Packages are not checked since their users can't act on the
+/// warning.
+fn check_shadowing(vm: &mut Vm, ident: ast::Ident, init: Option<ast::Expr>) {
+    let Some(base) = vm.scopes.base else { return };
+    if !vm.scopes.scopes.is_empty()
+        || ident.span().id().map_or(true, |id| id.package().is_some())
+        || !base.global.scope().is_constant(&ident)
+        || vm.scopes.top.get(&ident).is_some()
+        || init.is_some_and(|init| derives_from(init, &ident))
+    {
+        return;
+    }
+
+    let name = ident.as_str();
+    vm.engine.tracer.warn(warning!(
+        ident.span(),
+        "`{name}` shadows the standard library function of the same name";
+        hint: "set and show rules cannot refer to the original `{name}` anymore";
+        hint: "to shadow it intentionally, derive the new binding from the original, \
+               e.g. with `{name}.with(..)`",
+    ));
+}
+
+/// Whether the initializer of a binding refers to the given name.
+///
+/// The name of a named closure and its uses in the closure's body refer to the
+/// closure itself, so only the defaults of its parameters count.
+fn derives_from(init: ast::Expr, name: &str) -> bool {
+    let ast::Expr::Closure(closure) = init else {
+        return refers_to(init.to_untyped(), name);
+    };
+
+    closure.params().children().any(|param| match param {
+        ast::Param::Default(param) => refers_to(param.expr().to_untyped(), name),
+        ast::Param::Named(named) => refers_to(named.expr().to_untyped(), name),
+        _ => false,
+    }) || (closure.name().is_none() && refers_to(closure.body().to_untyped(), name))
+}
+
+/// Whether the syntax node contains an identifier with the given name in a
+/// value position.
+///
+/// The names of named arguments and accessed fields are no references.
+fn refers_to(node: &SyntaxNode, name: &str) -> bool {
+    if let Some(named) = node.cast::<ast::Named>() {
+        return refers_to(named.expr().to_untyped(), name);
+    }
+
+    if let Some(access) = node.cast::<ast::FieldAccess>() {
+        return refers_to(access.target().to_untyped(), name);
+    }
+
+    node.cast::<ast::Ident>().is_some_and(|ident| ident.as_str() == name)
+        || node.children().any(|child| refers_to(child, name))
+}
+
 /// Destructures a value into a pattern.
 pub(crate) fn destructure(
     vm: &mut Vm,
     pattern: ast::Pattern,
     value: Value,
 ) -> SourceResult<()> {
-    destructure_impl(vm, pattern, value, |vm, expr, value| match expr {
+    destructure_impl(vm, pattern, value, &|vm, expr, value| match expr {
         ast::Expr::Ident(ident) => {
             vm.define(ident, value);
             Ok(())
         }
-        _ => bail!(expr.span(), "nested patterns are currently not supported"),
+        _ => bail!(expr.span(), "cannot assign to this expression in a pattern"),
     })
 }
 
@@ -62,7 +130,7 @@ fn destructure_impl<T>(
     vm: &mut Vm,
     pattern: ast::Pattern,
     value: Value,
-    f: T,
+    f: &T,
 ) -> SourceResult<()>
 where
     T: Fn(&mut Vm, ast::Expr, Value) -> SourceResult<()>,
@@ -74,6 +142,9 @@ where
         ast::Pattern::Placeholder(_) => {}
         ast::Pattern::Destructuring(destruct) => match value {
             Value::Array(value) => destructure_array(vm, pattern, value, f, destruct)?,
+            Value::Range(value) => {
+                destructure_array(vm, pattern, value.to_array(), f, destruct)?
+            }
             Value::Dict(value) => destructure_dict(vm, value, f, destruct)?,
             _ => bail!(pattern.span(), "cannot destructure {}", value.ty()),
         },
@@ -85,7 +156,7 @@ fn destructure_array<F>(
     vm: &mut Vm,
     pattern: ast::Pattern,
     value: Array,
-    f: F,
+    f: &F,
     destruct: ast::Destructuring,
 ) -> SourceResult<()>
 where
@@ -114,6 +185,13 @@ where
                     bail!(pattern.span(), "not enough elements to destructure")
                 }
             }
+            ast::DestructuringKind::Nested(nested) => {
+                let Ok(v) = value.at(i as i64, None) else {
+                    bail!(nested.span(), "not enough elements to destructure");
+                };
+                destructure_impl(vm, ast::Pattern::Destructuring(nested), v, f)?;
+                i += 1;
+            }
             ast::DestructuringKind::Named(named) => {
                 bail!(named.span(), "cannot destructure named elements from an array")
             }
@@ -136,7 +214,7 @@ where
 fn destructure_dict<F>(
     vm: &mut Vm,
     dict: Dict,
-    f: F,
+    f: &F,
     destruct: ast::Destructuring,
 ) -> SourceResult<()>
 where
@@ -155,13 +233,16 @@ where
             ast::DestructuringKind::Named(named) => {
                 let name = named.name();
                 let v = dict.get(&name).at(name.span())?;
-                f(vm, named.expr(), v.clone())?;
+                destructure_impl(vm, named.pattern(), v.clone(), f)?;
                 used.insert(name.as_str());
             }
             ast::DestructuringKind::Placeholder(_) => {}
             ast::DestructuringKind::Normal(expr) => {
                 bail!(expr.span(), "expected key, found expression");
             }
+            ast::DestructuringKind::Nested(nested) => {
+                bail!(nested.span(), "expected key, found destructuring pattern");
+            }
         }
     }
 
@@ -3,6 +3,7 @@ use ecow::EcoVec;
 
 use crate::diag::{bail, error, At, HintedStrResult, SourceResult, Trace, Tracepoint};
 use crate::engine::Engine;
+use crate::eval::code::missing_field;
 use crate::eval::{Access, Eval, FlowEvent, Route, Tracer, Vm};
 use crate::foundations::{
     call_method_mut, is_mutating_method, Arg, Args, Bytes, Closure, Content, Func,
@@ -28,7 +29,7 @@ impl Eval for ast::FuncCall<'_> {
         let args = self.args();
         let trailing_comma = args.trailing_comma();
 
-        if !vm.engine.route.within(Route::MAX_CALL_DEPTH) {
+        if !vm.engine.route.within(vm.world().library().limits.call_depth) {
             bail!(span, "maximum function call depth exceeded");
         }
 
@@ -39,10 +40,15 @@ impl Eval for ast::FuncCall<'_> {
             let field = access.field();
             let field_span = field.span();
 
-            let target = if is_mutating_method(&field) {
+            let mut target = if is_mutating_method(&field) {
                 let mut args = args.eval(vm)?;
                 let target = target.access(vm)?;
 
+                // Ranges turn into arrays when they are mutated.
+                if let Value::Range(range) = *target {
+                    *target = Value::Array(range.to_array());
+                }
+
                 // Only arrays and dictionaries have mutable methods.
                 if matches!(target, Value::Array(_) | Value::Dict(_)) {
                     args.span = span;
@@ -61,6 +67,13 @@ impl Eval for ast::FuncCall<'_> {
 
             let mut args = args.eval(vm)?;
 
+            // Ranges support all array methods by turning into an array.
+            if let Value::Range(range) = target {
+                if target.ty().scope().get(&field).is_none() {
+                    target = Value::Array(range.to_array());
+                }
+            }
+
             // Handle plugins.
             if let Value::Plugin(plugin) = &target {
                 let bytes = args.all::<Bytes>()?;
@@ -90,7 +103,11 @@ impl Eval for ast::FuncCall<'_> {
                 target,
                 Value::Symbol(_) | Value::Func(_) | Value::Type(_) | Value::Module(_)
             ) {
-                (target.field(&field).at(field_span)?, args)
+                let callee = target
+                    .field(&field)
+                    .map_err(|message| missing_field(&target, message))
+                    .at(field_span)?;
+                (callee, args)
             } else {
                 let mut error = error!(
                     field_span,
@@ -190,6 +207,13 @@ impl Eval for ast::Args<'_> {
                             value: Spanned::new(value, span),
                         }));
                     }
+                    Value::Range(range) => {
+                        items.extend(range.iter().map(|value| Arg {
+                            span,
+                            name: None,
+                            value: Spanned::new(value.into_value(), span),
+                        }));
+                    }
                     Value::Dict(dict) => {
                         items.extend(dict.into_iter().map(|(key, value)| Arg {
                             span,
@@ -212,29 +236,66 @@ impl Eval for ast::Closure<'_> {
 
     #[tracing::instrument(name = "Closure::eval", skip_all)]
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        // Evaluate default values of named parameters.
-        let mut defaults = Vec::new();
-        for param in self.params().children() {
-            if let ast::Param::Named(named) = param {
-                defaults.push(named.expr().eval(vm)?);
-            }
+        eval_closure(vm, self, None)
+    }
+}
+
+/// Evaluate a closure. If it is bound to a name by a `let` binding, it can
+/// refer to itself under that name.
+pub(crate) fn eval_closure(
+    vm: &mut Vm,
+    closure: ast::Closure,
+    binding: Option<ast::Ident>,
+) -> SourceResult<Value> {
+    // Evaluate default values of parameters.
+    let mut defaults = Vec::new();
+    for param in closure.params().children() {
+        match param {
+            ast::Param::Default(param) => defaults.push(param.expr().eval(vm)?),
+            ast::Param::Named(named) => defaults.push(named.expr().eval(vm)?),
+            _ => {}
+        }
+    }
+
+    // Collect captured variables. The binding itself is provided when the
+    // closure is called, so it must not be captured.
+    let captured = {
+        let mut visitor = CapturesVisitor::new(Some(&vm.scopes));
+        if let Some(ident) = binding {
+            visitor.bind(ident);
         }
+        visitor.visit(closure.to_untyped());
+        visitor.finish()
+    };
 
-        // Collect captured variables.
-        let captured = {
-            let mut visitor = CapturesVisitor::new(Some(&vm.scopes));
-            visitor.visit(self.to_untyped());
-            visitor.finish()
-        };
+    // Define the closure.
+    let span = closure.params().span();
+    let closure = Closure {
+        node: closure.to_untyped().clone(),
+        defaults,
+        captured,
+        binding: binding.map(|ident| ident.get().clone()),
+    };
 
-        // Define the closure.
-        let closure = Closure {
-            node: self.to_untyped().clone(),
-            defaults,
-            captured,
-        };
+    Ok(Value::Func(Func::from(closure).spanned(span)))
+}
 
-        Ok(Value::Func(Func::from(closure).spanned(self.params().span())))
+/// The closure and name of a `let` binding of the form `let f = (..) => ..`,
+/// through which the closure can call itself recursively.
+///
+/// This only applies if the name is not defined yet. Otherwise, the closure
+/// refers to the existing definition, so that wrappers like
+/// `{let text = (..args) => text(..args, fill: red)}` keep working.
+pub(crate) fn recursive_binding<'a>(
+    binding: ast::LetBinding<'a>,
+    defined: impl FnOnce(&str) -> bool,
+) -> Option<(ast::Closure<'a>, ast::Ident<'a>)> {
+    match (binding.kind(), binding.init()?) {
+        (
+            ast::LetBindingKind::Normal(ast::Pattern::Normal(ast::Expr::Ident(ident))),
+            ast::Expr::Closure(closure),
+        ) if closure.name().is_none() && !defined(&ident) => Some((closure, ident)),
+        _ => None,
     }
 }
 
@@ -275,17 +336,25 @@ pub(crate) fn call_closure(
     // Provide the closure itself for recursive calls.
     if let Some(name) = node.name() {
         vm.define(name, Value::Func(func.clone()));
+    } else if let Some(name) = &closure.binding {
+        vm.scopes.top.define(name.clone(), Value::Func(func.clone()));
     }
 
-    // Parse the arguments according to the parameter list.
-    let num_pos_params = node
-        .params()
-        .children()
-        .filter(|p| matches!(p, ast::Param::Pos(_)))
-        .count();
+    // Parse the arguments according to the parameter list. Positional
+    // arguments first go to the required positional parameters, then to the
+    // positional parameters with defaults (from left to right) and finally
+    // to the sink.
+    let (num_pos_params, num_default_params) =
+        node.params().children().fold((0, 0), |(pos, default), p| match p {
+            ast::Param::Pos(_) => (pos + 1, default),
+            ast::Param::Default(_) => (pos, default + 1),
+            _ => (pos, default),
+        });
 
     let num_pos_args = args.to_pos().len();
-    let sink_size = num_pos_args.checked_sub(num_pos_params);
+    let surplus = num_pos_args.checked_sub(num_pos_params);
+    let mut num_filled_defaults = surplus.unwrap_or(0).min(num_default_params);
+    let sink_size = surplus.map(|surplus| surplus - num_filled_defaults);
 
     let mut sink = None;
     let mut sink_pos_values = None;
@@ -305,6 +374,17 @@ pub(crate) fn call_closure(
                     )?;
                 }
             },
+            ast::Param::Default(param) => {
+                let name = param.name();
+                let default = defaults.next().unwrap();
+                let value = if num_filled_defaults > 0 {
+                    num_filled_defaults -= 1;
+                    args.expect::<Value>(&name)?
+                } else {
+                    default.clone()
+                };
+                vm.define(name, value);
+            }
             ast::Param::Sink(ident) => {
                 sink = ident.name();
                 if let Some(sink_size) = sink_size {
@@ -406,8 +486,12 @@ impl<'a> CapturesVisitor<'a> {
             // of named parameters cannot access previous parameter bindings.
             Some(ast::Expr::Closure(expr)) => {
                 for param in expr.params().children() {
-                    if let ast::Param::Named(named) = param {
-                        self.visit(named.expr().to_untyped());
+                    match param {
+                        ast::Param::Default(param) => {
+                            self.visit(param.expr().to_untyped())
+                        }
+                        ast::Param::Named(named) => self.visit(named.expr().to_untyped()),
+                        _ => {}
                     }
                 }
 
@@ -423,6 +507,7 @@ impl<'a> CapturesVisitor<'a> {
                                 self.bind(ident);
                             }
                         }
+                        ast::Param::Default(param) => self.bind(param.name()),
                         ast::Param::Named(named) => self.bind(named.name()),
                         ast::Param::Sink(spread) => {
                             self.bind(spread.name().unwrap_or_default())
@@ -437,7 +522,19 @@ impl<'a> CapturesVisitor<'a> {
             // A let expression contains a binding, but that binding is only
             // active after the body is evaluated.
             Some(ast::Expr::Let(expr)) => {
-                if let Some(init) = expr.init() {
+                let defined = |name: &str| {
+                    self.internal.get(name).is_ok()
+                        || self
+                            .external
+                            .is_some_and(|external| external.get(name).is_ok())
+                };
+                if let Some((closure, ident)) = recursive_binding(expr, defined) {
+                    // A closure bound to a name can refer to itself.
+                    self.internal.enter();
+                    self.bind(ident);
+                    self.visit(closure.to_untyped());
+                    self.internal.exit();
+                } else if let Some(init) = expr.init() {
                     self.visit(init.to_untyped());
                 }
 
@@ -446,9 +543,9 @@ impl<'a> CapturesVisitor<'a> {
                 }
             }
 
-            // A for loop contains one or two bindings in its pattern. These are
-            // active after the iterable is evaluated but before the body is
-            // evaluated.
+            // A for loop contains bindings in its pattern, which may be nested.
+            // These are active after the iterable is evaluated but before the
+            // body is evaluated.
             Some(ast::Expr::For(expr)) => {
                 self.visit(expr.iter().to_untyped());
                 self.internal.enter();
@@ -546,6 +643,9 @@ mod tests {
         test("#let f(x, y) = x + y", &[]);
         test("#let f(x, y) = f", &[]);
         test("#let f = (x, y) => f", &["f"]);
+        test("#let g = (x, y) => g + y", &["y"]);
+        test("#let x = () => x + y", &["x", "y"]);
+        test("#let (x,) = (() => x,)", &["x"]);
 
         // Closure with different kinds of params.
         test("#((x, y) => x + z)", &["z"]);
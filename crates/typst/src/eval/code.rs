@@ -1,8 +1,8 @@
-use ecow::{eco_vec, EcoVec};
+use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 
-use crate::diag::{bail, error, At, SourceDiagnostic, SourceResult};
+use crate::diag::{bail, error, At, HintedString, SourceDiagnostic, SourceResult};
 use crate::eval::{ops, Eval, Vm};
-use crate::foundations::{Array, Content, Dict, Str, Value};
+use crate::foundations::{Array, Content, Dict, IntoValue, Str, Value};
 use crate::syntax::ast::{self, AstNode};
 
 impl Eval for ast::Code<'_> {
@@ -223,6 +223,9 @@ impl Eval for ast::Array<'_> {
                 ast::ArrayItem::Spread(expr) => match expr.eval(vm)? {
                     Value::None => {}
                     Value::Array(array) => vec.extend(array.into_iter()),
+                    Value::Range(range) => {
+                        vec.extend(range.iter().map(IntoValue::into_value))
+                    }
                     v => bail!(expr.span(), "cannot spread {} into array", v.ty()),
                 },
             }
@@ -312,6 +315,33 @@ impl Eval for ast::FieldAccess<'_> {
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
         let value = self.target().eval(vm)?;
         let field = self.field();
-        value.field(&field).at(field.span())
+        value
+            .field(&field)
+            .map_err(|message| missing_field(&value, message))
+            .at(field.span())
     }
 }
+
+/// Enrich a failed field access with a hint listing the available fields.
+pub(crate) fn missing_field(value: &Value, message: EcoString) -> HintedString {
+    /// How many fields to list at most.
+    const MAX_LISTED: usize = 10;
+
+    let mut error = HintedString::from(message);
+    let mut names = value.field_names();
+    if names.is_empty() {
+        return error;
+    }
+
+    names.sort();
+    let rest = names.len().saturating_sub(MAX_LISTED);
+    names.truncate(MAX_LISTED);
+
+    let mut hint = eco_format!("available fields are {}", names.join(", "));
+    if rest > 0 {
+        hint.push_str(&eco_format!(", and {rest} more"));
+    }
+
+    error.hints.push(hint);
+    error
+}
@@ -6,8 +6,8 @@ use crate::foundations::{IntoValue, Value};
 use crate::syntax::ast::{self, AstNode};
 use crate::syntax::{Span, SyntaxKind, SyntaxNode};
 
-/// The maximum number of loop iterations.
-const MAX_ITERATIONS: usize = 10_000;
+/// The default maximum number of loop iterations.
+pub(crate) const MAX_ITERATIONS: usize = 10_000;
 
 /// A control flow event that occurred during evaluation.
 #[derive(Debug, Clone, PartialEq)]
@@ -65,6 +65,7 @@ impl Eval for ast::WhileLoop<'_> {
 
         let condition = self.condition();
         let body = self.body();
+        let max = vm.world().library().limits.iterations;
 
         while condition.eval(vm)?.cast::<bool>().at(condition.span())? {
             if i == 0
@@ -72,7 +73,7 @@ impl Eval for ast::WhileLoop<'_> {
                 && !can_diverge(body.to_untyped())
             {
                 bail!(condition.span(), "condition is always true");
-            } else if i >= MAX_ITERATIONS {
+            } else if i >= max {
                 bail!(self.span(), "loop seems to be infinite");
             }
 
@@ -137,7 +138,6 @@ impl Eval for ast::ForLoop<'_> {
 
         let iter = self.iter().eval(vm)?;
         let pattern = self.pattern();
-
         match (&pattern, iter.clone()) {
             (ast::Pattern::Normal(_), Value::Str(string)) => {
                 // Iterate over graphemes of string.
@@ -151,6 +151,11 @@ impl Eval for ast::ForLoop<'_> {
                 // Iterate over values of array.
                 iter!(for pattern in array);
             }
+            (_, Value::Range(range)) => {
+                // Iterate over the integers of the range, without materializing
+                // them as an array.
+                iter!(for pattern in range);
+            }
             (ast::Pattern::Normal(_), _) => {
                 bail!(self.iter().span(), "cannot loop over {}", iter.ty());
             }
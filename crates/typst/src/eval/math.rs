@@ -12,11 +12,24 @@ impl Eval for ast::Math<'_> {
 
     #[tracing::instrument(name = "Math::eval", skip_all)]
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        Ok(Content::sequence(
-            self.exprs()
-                .map(|expr| expr.eval_display(vm))
-                .collect::<SourceResult<Vec<_>>>()?,
-        ))
+        let flow = vm.flow.take();
+        let mut seq = Vec::with_capacity(self.exprs().size_hint().1.unwrap_or_default());
+
+        for expr in self.exprs() {
+            seq.push(expr.eval_display(vm)?);
+
+            // Keep what was evaluated so far, but don't evaluate anything
+            // after a break, continue, or return.
+            if vm.flow.is_some() {
+                break;
+            }
+        }
+
+        if flow.is_some() {
+            vm.flow = flow;
+        }
+
+        Ok(Content::sequence(seq))
     }
 }
 
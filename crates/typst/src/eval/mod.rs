@@ -34,6 +34,10 @@ use crate::syntax::{ast, parse, parse_code, parse_math, Source, Span};
 use crate::World;
 
 /// Evaluate a source file and return the resulting module.
+///
+/// The result is cached based on the source and everything the evaluation
+/// depends on, so an imported module is only re-evaluated when its own source
+/// or one of its dependencies changes.
 #[comemo::memoize]
 #[tracing::instrument(skip_all)]
 pub fn eval(
@@ -48,6 +52,9 @@ pub fn eval(
         panic!("Tried to cyclicly evaluate {:?}", id.vpath());
     }
 
+    let mut tracer = tracer;
+    tracer.report(Progress::Evaluating(id));
+
     // Prepare the engine.
     let mut locator = Locator::new();
     let introspector = Introspector::default();
@@ -153,6 +160,28 @@ pub fn eval_string(
     Ok(output)
 }
 
+/// Limits for the evaluation of code.
+///
+/// They protect against runaway recursion and infinite loops. The defaults are
+/// safe for most documents, but large legitimate computations may need to
+/// raise them. Note that very deep recursion can exhaust the native stack.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Limits {
+    /// The maximum depth of nested function calls.
+    pub call_depth: usize,
+    /// The maximum number of iterations of a single `while` loop.
+    pub iterations: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            call_depth: Route::MAX_CALL_DEPTH,
+            iterations: MAX_ITERATIONS,
+        }
+    }
+}
+
 /// In which mode to evaluate a string.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
 pub enum EvalMode {
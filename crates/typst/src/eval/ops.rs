@@ -125,6 +125,9 @@ pub fn join(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Str(a), Content(b)) => Content(TextElem::packed(a) + b),
         (Symbol(a), Content(b)) => Content(TextElem::packed(a.get()) + b),
         (Array(a), Array(b)) => Array(a + b),
+        (Range(a), Array(b)) => Array(a.to_array() + b),
+        (Array(a), Range(b)) => Array(a + b.to_array()),
+        (Range(a), Range(b)) => Array(a.to_array() + b.to_array()),
         (Dict(a), Dict(b)) => Dict(a + b),
 
         // Type compatibility.
@@ -217,6 +220,9 @@ pub fn add(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Symbol(a), Content(b)) => Content(TextElem::packed(a.get()) + b),
 
         (Array(a), Array(b)) => Array(a + b),
+        (Range(a), Array(b)) => Array(a.to_array() + b),
+        (Array(a), Range(b)) => Array(a + b.to_array()),
+        (Range(a), Range(b)) => Array(a.to_array() + b.to_array()),
         (Dict(a), Dict(b)) => Dict(a + b),
 
         (Color(color), Length(thickness)) | (Length(thickness), Color(color)) => Stroke {
@@ -344,6 +350,8 @@ pub fn mul(lhs: Value, rhs: Value) -> StrResult<Value> {
         (Int(a), Str(b)) => Str(b.repeat(Value::Int(a).cast()?)?),
         (Array(a), Int(b)) => Array(a.repeat(Value::Int(b).cast()?)?),
         (Int(a), Array(b)) => Array(b.repeat(Value::Int(a).cast()?)?),
+        (Range(a), Int(b)) => Array(a.to_array().repeat(Value::Int(b).cast()?)?),
+        (Int(a), Range(b)) => Array(b.to_array().repeat(Value::Int(a).cast()?)?),
         (Content(a), b @ Int(_)) => Content(a.repeat(b.cast()?)),
         (a @ Int(_), Content(b)) => Content(b.repeat(a.cast()?)),
 
@@ -500,6 +508,7 @@ pub fn equal(lhs: &Value, rhs: &Value) -> bool {
         (Label(a), Label(b)) => a == b,
         (Content(a), Content(b)) => a == b,
         (Array(a), Array(b)) => a == b,
+        (Range(a), Range(b)) => a == b,
         (Dict(a), Dict(b)) => a == b,
         (Func(a), Func(b)) => a == b,
         (Args(a), Args(b)) => a == b,
@@ -517,6 +526,10 @@ pub fn equal(lhs: &Value, rhs: &Value) -> bool {
         (&Ratio(a), &Relative(b)) => a == b.rel && b.abs.is_zero(),
         (&Relative(a), &Length(b)) => a.abs == b && a.rel.is_zero(),
         (&Relative(a), &Ratio(b)) => a.rel == b && a.abs.is_zero(),
+        (Range(a), Array(b)) | (Array(b), Range(a)) => {
+            a.len() == b.len() as u64
+                && a.iter().zip(b.iter()).all(|(x, y)| equal(&Int(x), y))
+        }
 
         // Type compatibility.
         (Type(a), Str(b)) => a.compat_name() == b.as_str(),
@@ -594,6 +607,11 @@ pub fn contains(lhs: &Value, rhs: &Value) -> Option<bool> {
         (Dyn(a), Str(b)) => a.downcast::<Regex>().map(|regex| regex.is_match(b)),
         (Str(a), Dict(b)) => Some(b.contains(a)),
         (a, Array(b)) => Some(b.contains(a.clone())),
+        (a, Range(b)) => Some(b.contains(a.clone())),
+        (Int(a), Bytes(b)) => Some(u8::try_from(*a).map_or(false, |a| b.contains(&a))),
+        (Bytes(a), Bytes(b)) => {
+            Some(a.is_empty() || b.windows(a.len()).any(|window| window == &**a))
+        }
 
         // Type compatibility.
         (Type(a), Str(b)) => Some(b.as_str().contains(a.compat_name())),
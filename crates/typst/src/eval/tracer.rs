@@ -1,9 +1,10 @@
 use std::collections::HashSet;
+use std::sync::Arc;
 
 use ecow::EcoVec;
 
 use crate::diag::SourceDiagnostic;
-use crate::foundations::Value;
+use crate::foundations::{Selector, Value};
 use crate::syntax::{FileId, Span};
 use crate::util::hash128;
 
@@ -11,10 +12,15 @@ use crate::util::hash128;
 #[derive(Default, Clone)]
 pub struct Tracer {
     inspected: Option<Span>,
+    lenient: bool,
+    progress: Option<Arc<dyn Fn(Progress) + Send + Sync>>,
     warnings: EcoVec<SourceDiagnostic>,
     warnings_set: HashSet<u128>,
     delayed: EcoVec<SourceDiagnostic>,
     values: EcoVec<Value>,
+    trace_rules: bool,
+    rules: EcoVec<RuleApplication>,
+    rules_set: HashSet<u128>,
 }
 
 impl Tracer {
@@ -31,6 +37,13 @@ impl Tracer {
         std::mem::take(&mut self.delayed)
     }
 
+    /// Clear the recorded show rule applications, so that only the ones of
+    /// the final layout iteration remain.
+    pub(crate) fn clear_rules(&mut self) {
+        self.rules.clear();
+        self.rules_set.clear();
+    }
+
     /// Get the stored warnings.
     pub fn warnings(self) -> EcoVec<SourceDiagnostic> {
         self.warnings
@@ -42,10 +55,72 @@ impl Tracer {
         self.inspected = Some(span);
     }
 
+    /// Whether to replace images that fail to load with a visible
+    /// placeholder and a warning instead of failing the compilation.
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
+    /// Register a sink that is notified whenever compilation enters a new
+    /// phase. This can be used to show progress for long compilations.
+    pub fn set_progress(&mut self, sink: impl Fn(Progress) + Send + Sync + 'static) {
+        self.progress = Some(Arc::new(sink));
+    }
+
     /// Get the values for the inspected span.
     pub fn values(self) -> EcoVec<Value> {
         self.values
     }
+
+    /// Whether to record every application of a show rule. The log can be
+    /// retrieved via `rules` after compilation.
+    pub fn set_trace_rules(&mut self, trace: bool) {
+        self.trace_rules = trace;
+    }
+
+    /// The show rule applications recorded during the final layout
+    /// iteration, in the order in which they first happened. Applications
+    /// that are repeated because content is laid out more than once are
+    /// only recorded once.
+    pub fn rules(&self) -> &[RuleApplication] {
+        &self.rules
+    }
+}
+
+/// An application of a show rule to an element, as recorded by a [`Tracer`].
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct RuleApplication {
+    /// The selector of the show rule.
+    pub selector: Option<Selector>,
+    /// The span of the show rule.
+    pub rule: Span,
+    /// The span of the element the rule was applied to.
+    pub target: Span,
+}
+
+/// A phase of compilation, as reported to the progress sink of a [`Tracer`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Progress {
+    /// A source file is being evaluated. This is reported for the main file
+    /// and for each imported or included file.
+    Evaluating(FileId),
+    /// The document is being laid out. Layout is repeated until all
+    /// introspections stabilize, so there can be multiple iterations.
+    Layout {
+        /// The layout iteration, starting at zero.
+        iteration: usize,
+        /// The number of pages produced by the previous iteration, as an
+        /// estimate of the final page count. Zero in the first iteration.
+        pages: usize,
+    },
+    /// The document was compiled successfully.
+    Done {
+        /// The number of pages in the finished document.
+        pages: usize,
+    },
+    /// The document is being exported. Since exporting happens outside of
+    /// compilation, this is only reported by embedders that export.
+    Exporting,
 }
 
 #[comemo::track]
@@ -73,6 +148,31 @@ impl Tracer {
         }
     }
 
+    /// Whether broken resources should be replaced by placeholders.
+    pub fn lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Whether show rule applications should be recorded.
+    pub fn traces_rules(&self) -> bool {
+        self.trace_rules
+    }
+
+    /// Record the application of a show rule.
+    pub fn rule(&mut self, application: RuleApplication) {
+        // Check if the application is a duplicate.
+        if self.rules_set.insert(hash128(&application)) {
+            self.rules.push(application);
+        }
+    }
+
+    /// Report progress to the registered sink, if any.
+    pub fn report(&mut self, progress: Progress) {
+        if let Some(sink) = &self.progress {
+            sink(progress);
+        }
+    }
+
     /// Trace a value for the span.
     pub fn value(&mut self, v: Value) {
         if self.values.len() < Self::MAX_VALUES {
@@ -7,14 +7,15 @@ use ecow::{eco_format, EcoString, EcoVec};
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 
-use crate::diag::{At, SourceResult, StrResult};
+use crate::diag::{bail, At, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::eval::ops;
 use crate::foundations::{
     cast, func, repr, scope, ty, Args, Bytes, CastInfo, FromValue, Func, IntoValue,
-    Reflect, Repr, Value, Version,
+    Range, Reflect, Repr, Str, Type, Value, Version,
 };
 use crate::syntax::Span;
+use crate::text::{CollationKey, Lang};
 
 /// Create a new [`Array`] from values.
 #[macro_export]
@@ -344,15 +345,15 @@ impl Array {
     /// `end` of the range. If you pass two, they describe the `start` and `end`
     /// of the range.
     ///
-    /// This function is available both in the array function's scope and
-    /// globally.
+    /// The global [`range`]($range) function takes the same arguments, but
+    /// produces a lazy range instead of an array.
     ///
     /// ```example
-    /// #range(5) \
-    /// #range(2, 5) \
-    /// #range(20, step: 4) \
-    /// #range(21, step: 4) \
-    /// #range(5, 2, step: -1)
+    /// #array.range(5) \
+    /// #array.range(2, 5) \
+    /// #array.range(20, step: 4) \
+    /// #array.range(21, step: 4) \
+    /// #array.range(5, 2, step: -1)
     /// ```
     #[func]
     pub fn range(
@@ -371,23 +372,7 @@ impl Array {
         #[default(NonZeroI64::new(1).unwrap())]
         step: NonZeroI64,
     ) -> SourceResult<Array> {
-        let first = args.expect::<i64>("end")?;
-        let (start, end) = match args.eat::<i64>()? {
-            Some(second) => (first, second),
-            None => (0, first),
-        };
-
-        let step = step.get();
-
-        let mut x = start;
-        let mut array = Self::new();
-
-        while x.cmp(&end) == 0.cmp(&step) {
-            array.push(x.into_value());
-            x += step;
-        }
-
-        Ok(array)
+        Ok(Range::construct(args, step)?.to_array())
     }
 
     /// Produces a new array with only the items from the original one for which
@@ -616,10 +601,10 @@ impl Array {
     pub fn flatten(self) -> Array {
         let mut flat = EcoVec::with_capacity(self.0.len());
         for item in self {
-            if let Value::Array(nested) = item {
-                flat.extend(nested.flatten());
-            } else {
-                flat.push(item);
+            match item {
+                Value::Array(nested) => flat.extend(nested.flatten()),
+                Value::Range(nested) => flat.extend(nested.to_array()),
+                item => flat.push(item),
             }
         }
         flat.into()
@@ -709,6 +694,12 @@ impl Array {
     ///
     /// Returns an error if two values could not be compared or if the key
     /// function (if given) yields an error.
+    ///
+    /// ```example
+    /// #("Zucker", "Äpfel", "Apfel").sorted() \
+    /// #("Zucker", "Äpfel", "Apfel").sorted(lang: "de") \
+    /// #("Zucker", "Äpfel", "Apfel").sorted(lang: "sv")
+    /// ```
     #[func]
     pub fn sorted(
         self,
@@ -720,6 +711,11 @@ impl Array {
         /// determine the keys to sort by.
         #[named]
         key: Option<Func>,
+        /// If given, strings are sorted in the alphabetical order of this
+        /// language instead of by their Unicode code points. For instance,
+        /// "ä" sorts like "a" in German, but after "z" in Swedish.
+        #[named]
+        lang: Option<Lang>,
     ) -> SourceResult<Array> {
         let mut result = Ok(());
         let mut vec = self.0;
@@ -729,6 +725,25 @@ impl Array {
             Some(f) => f.call(engine, [x]),
             None => Ok(x),
         };
+
+        if let Some(lang) = lang {
+            // Collation keys are expensive, so compute them once per element
+            // instead of for every comparison.
+            let mut keyed = vec
+                .into_iter()
+                .map(|value| Ok((Collated::new(lang, key_of(value.clone())?), value)))
+                .collect::<SourceResult<Vec<_>>>()?;
+            keyed.sort_by(|(a, _), (b, _)| {
+                a.compare(b).unwrap_or_else(|err| {
+                    if result.is_ok() {
+                        result = Err(err).at(span);
+                    }
+                    Ordering::Equal
+                })
+            });
+            return result.map(|_| keyed.into_iter().map(|(_, value)| value).collect());
+        }
+
         vec.make_mut().sort_by(|a, b| {
             // Until we get `try` blocks :)
             match (key_of(a.clone()), key_of(b.clone())) {
@@ -938,6 +953,56 @@ impl<T: FromValue, const N: usize> FromValue for SmallVec<[T; N]> {
     }
 }
 
+/// A sort key whose strings are replaced by their collation keys.
+enum Collated {
+    /// A collated string.
+    Str(CollationKey),
+    /// An array, which is collated element by element.
+    Array(Vec<Collated>),
+    /// Any other value, which is compared as usual.
+    Other(Value),
+}
+
+impl Collated {
+    /// Collate the strings in a sort key.
+    fn new(lang: Lang, value: Value) -> Self {
+        match value {
+            Value::Str(string) => Self::Str(lang.collation_key(&string)),
+            Value::Array(array) => {
+                Self::Array(array.into_iter().map(|v| Self::new(lang, v)).collect())
+            }
+            value => Self::Other(value),
+        }
+    }
+
+    /// Compare two collated sort keys.
+    fn compare(&self, other: &Self) -> StrResult<Ordering> {
+        Ok(match (self, other) {
+            (Self::Str(a), Self::Str(b)) => a.cmp(b),
+            (Self::Array(a), Self::Array(b)) => {
+                for (x, y) in a.iter().zip(b) {
+                    match x.compare(y)? {
+                        Ordering::Equal => {}
+                        ordering => return Ok(ordering),
+                    }
+                }
+                a.len().cmp(&b.len())
+            }
+            (Self::Other(a), Self::Other(b)) => ops::compare(a, b)?,
+            _ => bail!("cannot compare {} and {}", self.ty(), other.ty()),
+        })
+    }
+
+    /// The type of the original value.
+    fn ty(&self) -> Type {
+        match self {
+            Self::Str(_) => Type::of::<Str>(),
+            Self::Array(_) => Type::of::<Array>(),
+            Self::Other(value) => value.ty(),
+        }
+    }
+}
+
 /// The error message when the array is empty.
 #[cold]
 fn array_is_empty() -> EcoString {
@@ -24,6 +24,9 @@ use crate::foundations::{cast, func, scope, ty, Array, Reflect, Repr, Str, Value
 /// When [reading]($read) data from a file, you can decide whether to load it
 /// as a string or as raw bytes.
 ///
+/// With the `{in}` operator, you can check whether a single byte (given as an
+/// integer) or a sequence of bytes is contained in a byte buffer.
+///
 /// ```example
 /// #bytes((123, 160, 22, 0)) \
 /// #bytes("Hello 😃")
@@ -39,6 +39,11 @@ use crate::util::fat;
 /// [joined together]($scripting/#blocks) and multiplied with integers. Wherever
 /// content is expected, you can also pass a [string]($str) or `{none}`.
 ///
+/// Two pieces of content are equal if they consist of the same elements with
+/// equal fields. Where in the source code the content was created and which
+/// label it has doesn't matter. Equal content also has the same
+/// [hash]($hash).
+///
 /// # Representation
 /// Content consists of elements with fields. When constructing an element with
 /// its _element function,_ you provide these fields as arguments and when you
@@ -210,17 +215,25 @@ impl Content {
     }
 
     /// Create a new sequence element from multiples elements.
+    ///
+    /// Nested sequences without a label or location are flattened into the
+    /// new one. This saves the allocations of the inner sequences, but each
+    /// child still lives in its own allocation.
     pub fn sequence(iter: impl IntoIterator<Item = Self>) -> Self {
-        let mut iter = iter.into_iter();
-        let Some(first) = iter.next() else { return Self::empty() };
-        let Some(second) = iter.next() else { return first };
-        SequenceElem::new(
-            std::iter::once(Prehashed::new(first))
-                .chain(std::iter::once(Prehashed::new(second)))
-                .chain(iter.map(Prehashed::new))
-                .collect(),
-        )
-        .into()
+        let iter = iter.into_iter();
+        let mut children = Vec::with_capacity(iter.size_hint().0);
+        for content in iter {
+            push_flattened(&mut children, content);
+        }
+
+        match children.len() {
+            0 => Self::empty(),
+            1 => children.pop().unwrap().into_inner(),
+            _ => {
+                children.shrink_to_fit();
+                SequenceElem::new(children).into()
+            }
+        }
     }
 
     /// Access the children if this is a sequence.
@@ -704,6 +717,28 @@ impl Serialize for Content {
     }
 }
 
+/// Push content into a list of sequence children, splicing in the children of
+/// nested sequences instead of keeping the sequence itself around.
+///
+/// Sequences that carry a label or location are kept intact since
+/// introspection needs to find them as a unit.
+fn push_flattened(children: &mut Vec<Prehashed<Content>>, content: Content) {
+    if !content.is_sequence()
+        || content.label().is_some()
+        || content.location().is_some()
+        || content.is_prepared()
+    {
+        children.push(Prehashed::new(content));
+        return;
+    }
+
+    let sequence = content.unpack::<SequenceElem>().unwrap();
+    match Arc::try_unwrap(sequence) {
+        Ok(sequence) => children.extend(sequence.children),
+        Err(shared) => children.extend(shared.children.iter().cloned()),
+    }
+}
+
 /// Defines the `ElemFunc` for sequences.
 #[elem(Repr, PartialEq)]
 struct SequenceElem {
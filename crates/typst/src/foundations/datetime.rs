@@ -4,14 +4,19 @@ use std::ops::{Add, Sub};
 
 use ecow::{eco_format, EcoString, EcoVec};
 use time::error::{Format, InvalidFormatDescription};
+use time::format_description::modifier::{MonthRepr, WeekdayRepr};
+use time::format_description::{Component, OwnedFormatItem};
 use time::macros::format_description;
 use time::{format_description, Month, PrimitiveDateTime};
 
-use crate::diag::{bail, StrResult};
+use crate::diag::{bail, warning, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, func, repr, scope, ty, Dict, Duration, Repr, Smart, Str, Value,
+    cast, func, repr, scope, ty, Dict, Duration, Repr, Smart, Str, StyleChain, Styles,
+    Value,
 };
+use crate::syntax::Span;
+use crate::text::{Lang, TextElem};
 use crate::World;
 
 /// Represents a date, a time, or a combination of both.
@@ -199,6 +204,15 @@ impl Datetime {
         }
     }
 
+    /// The date of this datetime, if it has one.
+    fn date(&self) -> Option<time::Date> {
+        match self {
+            Datetime::Datetime(datetime) => Some(datetime.date()),
+            Datetime::Date(date) => Some(*date),
+            Datetime::Time(_) => None,
+        }
+    }
+
     /// Which kind of variant this datetime stores.
     pub fn kind(&self) -> &'static str {
         match self {
@@ -317,28 +331,90 @@ impl Datetime {
     /// `[[year]-[month]-[day]]`. If you specified a time, it will be
     /// `[[hour]:[minute]:[second]]`. In the case of a datetime, it will be
     /// `[[year]-[month]-[day] [hour]:[minute]:[second]]`.
+    ///
+    /// If you specify a language through `lang` or `styles`, the default
+    /// format instead writes out the date in the way that is customary for
+    /// that language, for instance `{"1. März 2023"}` in German.
+    ///
+    /// ```example
+    /// #let date = datetime(year: 2023, month: 3, day: 1)
+    /// #date.display("[weekday], [day padding:none] [month repr:long]") \
+    /// #date.display(
+    ///   "[weekday] [day padding:none] [month repr:long]",
+    ///   lang: "fr",
+    /// ) \
+    /// #date.display(lang: "de")
+    ///
+    /// #set text(lang: "es")
+    /// #style(styles => date.display(styles: styles))
+    /// ```
     #[func]
     pub fn display(
         &self,
+        /// The engine.
+        engine: &mut Engine,
+        /// The callsite span.
+        span: Span,
         /// The format used to display the datetime.
         #[default]
         pattern: Smart<DisplayPattern>,
+        /// The language in which to display the names of months and weekdays.
+        /// Supported languages are Dutch, English, French, German, Italian,
+        /// Portuguese, and Spanish. Other languages fall back to English with
+        /// a warning.
+        ///
+        /// If set to `{auto}`, the [text language]($text.lang) of `styles` is
+        /// used, or English if no styles are given.
+        #[named]
+        #[default]
+        lang: Smart<Lang>,
+        /// The styles from which to take the language if `lang` is `{auto}`.
+        /// You can get the styles at a point in the document with the
+        /// [`style`]($style) function.
+        #[named]
+        #[default]
+        styles: Option<Styles>,
     ) -> StrResult<EcoString> {
-        let pat = |s| format_description::parse_borrowed::<2>(s).unwrap();
-        let result = match pattern {
-            Smart::Auto => match self {
-                Self::Date(date) => date.format(&pat("[year]-[month]-[day]")),
-                Self::Time(time) => time.format(&pat("[hour]:[minute]:[second]")),
-                Self::Datetime(datetime) => {
-                    datetime.format(&pat("[year]-[month]-[day] [hour]:[minute]:[second]"))
-                }
-            },
+        let localized = lang.is_custom() || styles.is_some();
+        let lang = lang.unwrap_or_else(|| match &styles {
+            Some(styles) => TextElem::lang_in(StyleChain::new(styles)),
+            None => Lang::ENGLISH,
+        });
+        let names = DateNames::of(lang);
+
+        let mut format = match pattern {
+            Smart::Custom(DisplayPattern(_, format)) => format,
+            Smart::Auto => {
+                let date = match names {
+                    Some(names) if localized => names.date,
+                    _ => "[year]-[month]-[day]",
+                };
+                let pattern = match self {
+                    Self::Date(_) => date.into(),
+                    Self::Time(_) => "[hour]:[minute]:[second]".into(),
+                    Self::Datetime(_) => eco_format!("{date} [hour]:[minute]:[second]"),
+                };
+                format_description::parse_owned::<2>(&pattern).unwrap()
+            }
+        };
 
-            Smart::Custom(DisplayPattern(_, format)) => match self {
-                Self::Date(date) => date.format(&format),
-                Self::Time(time) => time.format(&format),
-                Self::Datetime(datetime) => datetime.format(&format),
-            },
+        if let Some(date) = self.date() {
+            match names {
+                Some(names) => format = names.localize(format, date),
+                None if has_names(&format) => engine.tracer.warn(warning!(
+                    span,
+                    "month and weekday names are not available in language `{}`",
+                    lang.as_str();
+                    hint: "they are displayed in English instead"
+                )),
+                None => {}
+            }
+        }
+
+        let result = match self {
+            Self::Date(date) => date.format(&format),
+            Self::Time(time) => time.format(&format),
+            Self::Datetime(datetime) => datetime.format(&format),
         };
         result.map(EcoString::from).map_err(format_time_format_error)
     }
@@ -509,6 +585,303 @@ cast! {
     v: u8 => Self::try_from(v).map_err(|_| "month is invalid")?
 }
 
+/// The names of months and weekdays in a language.
+struct DateNames {
+    /// The customary way to write out a date, as a format description.
+    date: &'static str,
+    months: [&'static str; 12],
+    months_short: [&'static str; 12],
+    weekdays: [&'static str; 7],
+    weekdays_short: [&'static str; 7],
+}
+
+impl DateNames {
+    /// The names for a language, if they are known.
+    fn of(lang: Lang) -> Option<&'static Self> {
+        Some(match lang {
+            Lang::DUTCH => &DUTCH,
+            Lang::ENGLISH => &ENGLISH,
+            Lang::FRENCH => &FRENCH,
+            Lang::GERMAN => &GERMAN,
+            Lang::ITALIAN => &ITALIAN,
+            Lang::PORTUGUESE => &PORTUGUESE,
+            Lang::SPANISH => &SPANISH,
+            _ => return None,
+        })
+    }
+
+    /// Replace the textual month and weekday components of a format with the
+    /// names for the given date.
+    fn localize(&self, item: OwnedFormatItem, date: time::Date) -> OwnedFormatItem {
+        let month = date.month() as usize - 1;
+        let weekday = date.weekday().number_days_from_monday() as usize;
+        let literal = |name: &str| OwnedFormatItem::Literal(name.as_bytes().into());
+        let localize_all = |items: Box<[OwnedFormatItem]>| {
+            items
+                .into_vec()
+                .into_iter()
+                .map(|item| self.localize(item, date))
+                .collect()
+        };
+
+        match item {
+            OwnedFormatItem::Component(Component::Month(m))
+                if matches!(m.repr, MonthRepr::Long) =>
+            {
+                literal(self.months[month])
+            }
+            OwnedFormatItem::Component(Component::Month(m))
+                if matches!(m.repr, MonthRepr::Short) =>
+            {
+                literal(self.months_short[month])
+            }
+            OwnedFormatItem::Component(Component::Weekday(w))
+                if matches!(w.repr, WeekdayRepr::Long) =>
+            {
+                literal(self.weekdays[weekday])
+            }
+            OwnedFormatItem::Component(Component::Weekday(w))
+                if matches!(w.repr, WeekdayRepr::Short) =>
+            {
+                literal(self.weekdays_short[weekday])
+            }
+            OwnedFormatItem::Compound(items) => {
+                OwnedFormatItem::Compound(localize_all(items))
+            }
+            OwnedFormatItem::Optional(item) => {
+                OwnedFormatItem::Optional(Box::new(self.localize(*item, date)))
+            }
+            OwnedFormatItem::First(items) => OwnedFormatItem::First(localize_all(items)),
+            item => item,
+        }
+    }
+}
+
+const DUTCH: DateNames = DateNames {
+    date: "[day padding:none] [month repr:long] [year]",
+    months: [
+        "januari",
+        "februari",
+        "maart",
+        "april",
+        "mei",
+        "juni",
+        "juli",
+        "augustus",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ],
+    months_short: [
+        "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov",
+        "dec",
+    ],
+    weekdays: [
+        "maandag",
+        "dinsdag",
+        "woensdag",
+        "donderdag",
+        "vrijdag",
+        "zaterdag",
+        "zondag",
+    ],
+    weekdays_short: ["ma", "di", "wo", "do", "vr", "za", "zo"],
+};
+
+const ENGLISH: DateNames = DateNames {
+    date: "[month repr:long] [day padding:none], [year]",
+    months: [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ],
+    months_short: [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov",
+        "Dec",
+    ],
+    weekdays: [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ],
+    weekdays_short: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
+};
+
+const FRENCH: DateNames = DateNames {
+    date: "[day padding:none] [month repr:long] [year]",
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    months_short: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.",
+        "oct.", "nov.", "déc.",
+    ],
+    weekdays: ["lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi", "dimanche"],
+    weekdays_short: ["lun.", "mar.", "mer.", "jeu.", "ven.", "sam.", "dim."],
+};
+
+const GERMAN: DateNames = DateNames {
+    date: "[day padding:none]. [month repr:long] [year]",
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    months_short: [
+        "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov",
+        "Dez",
+    ],
+    weekdays: [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ],
+    weekdays_short: ["Mo", "Di", "Mi", "Do", "Fr", "Sa", "So"],
+};
+
+const ITALIAN: DateNames = DateNames {
+    date: "[day padding:none] [month repr:long] [year]",
+    months: [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ],
+    months_short: [
+        "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov",
+        "dic",
+    ],
+    weekdays: [
+        "lunedì",
+        "martedì",
+        "mercoledì",
+        "giovedì",
+        "venerdì",
+        "sabato",
+        "domenica",
+    ],
+    weekdays_short: ["lun", "mar", "mer", "gio", "ven", "sab", "dom"],
+};
+
+const PORTUGUESE: DateNames = DateNames {
+    date: "[day padding:none] de [month repr:long] de [year]",
+    months: [
+        "janeiro",
+        "fevereiro",
+        "março",
+        "abril",
+        "maio",
+        "junho",
+        "julho",
+        "agosto",
+        "setembro",
+        "outubro",
+        "novembro",
+        "dezembro",
+    ],
+    months_short: [
+        "jan", "fev", "mar", "abr", "mai", "jun", "jul", "ago", "set", "out", "nov",
+        "dez",
+    ],
+    weekdays: [
+        "segunda-feira",
+        "terça-feira",
+        "quarta-feira",
+        "quinta-feira",
+        "sexta-feira",
+        "sábado",
+        "domingo",
+    ],
+    weekdays_short: ["seg", "ter", "qua", "qui", "sex", "sáb", "dom"],
+};
+
+const SPANISH: DateNames = DateNames {
+    date: "[day padding:none] de [month repr:long] de [year]",
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    months_short: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov",
+        "dic",
+    ],
+    weekdays: ["lunes", "martes", "miércoles", "jueves", "viernes", "sábado", "domingo"],
+    weekdays_short: ["lun", "mar", "mié", "jue", "vie", "sáb", "dom"],
+};
+
+/// Whether a format contains textual month or weekday components.
+fn has_names(item: &OwnedFormatItem) -> bool {
+    match item {
+        OwnedFormatItem::Component(Component::Month(m)) => {
+            matches!(m.repr, MonthRepr::Long | MonthRepr::Short)
+        }
+        OwnedFormatItem::Component(Component::Weekday(w)) => {
+            matches!(w.repr, WeekdayRepr::Long | WeekdayRepr::Short)
+        }
+        OwnedFormatItem::Compound(items) | OwnedFormatItem::First(items) => {
+            items.iter().any(has_names)
+        }
+        OwnedFormatItem::Optional(item) => has_names(item),
+        _ => false,
+    }
+}
+
 /// Format the `Format` error of the time crate in an appropriate way.
 fn format_time_format_error(error: Format) -> EcoString {
     match error {
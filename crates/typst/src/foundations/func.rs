@@ -1,4 +1,5 @@
 use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use comemo::{Prehashed, TrackedMut};
@@ -8,8 +9,8 @@ use once_cell::sync::Lazy;
 use crate::diag::{bail, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, repr, scope, ty, Args, CastInfo, Content, Element, IntoArgs, Scope, Selector,
-    Type, Value,
+    cast, dict, repr, scope, ty, Args, CastInfo, Content, Dict, Element, IntoArgs, Scope,
+    Selector, Type, Value,
 };
 use crate::syntax::{ast, Span, SyntaxNode};
 use crate::util::Static;
@@ -67,8 +68,12 @@ pub use typst_macros::func;
 /// # Defining functions
 /// You can define your own function with a [let binding]($scripting/#bindings)
 /// that has a parameter list after the binding's name. The parameter list can
-/// contain mandatory positional parameters, named parameters with default
-/// values and [argument sinks]($arguments).
+/// contain mandatory positional parameters, optional positional parameters
+/// with default values (`{y = 2}`), named parameters with default values
+/// (`{y: 2}`) and [argument sinks]($arguments). Positional arguments are first
+/// assigned to the mandatory positional parameters. Any remaining ones fill the
+/// optional positional parameters from left to right and the rest ends up in
+/// the argument sink.
 ///
 /// The right-hand side of a function binding is the function body, which can be
 /// a block or any other expression. It defines the function's return value and
@@ -115,6 +120,16 @@ pub use typst_macros::func;
 /// once?
 /// ```
 ///
+/// When an unnamed function is directly bound to a name with `{let}` and the
+/// name is not defined yet, it can call itself under that name, just like a
+/// named function. If the name is already defined, the function refers to
+/// the existing definition instead, so that it can wrap it.
+///
+/// ```example
+/// #let fac = n => if n <= 1 { 1 } else { n * fac(n - 1) }
+/// #fac(5)
+/// ```
+///
 /// # Note on function purity
 /// In Typst, all functions are _pure._ This means that for the same
 /// arguments, they always return the same result. They cannot "remember" things to
@@ -144,18 +159,21 @@ enum Repr {
     Closure(Arc<Prehashed<Closure>>),
     /// A nested function with pre-applied arguments.
     With(Arc<(Func, Args)>),
+    /// Two functions where the result of the first is passed to the second.
+    Then(Arc<(Func, Func)>),
 }
 
 impl Func {
     /// The function's name (e.g. `min`).
     ///
-    /// Returns `None` if this is an anonymous closure.
+    /// Returns `None` if this is an anonymous closure or a composed function.
     pub fn name(&self) -> Option<&str> {
         match &self.repr {
             Repr::Native(native) => Some(native.name),
             Repr::Element(elem) => Some(elem.name()),
             Repr::Closure(closure) => closure.name(),
             Repr::With(with) => with.0.name(),
+            Repr::Then(_) => None,
         }
     }
 
@@ -168,6 +186,7 @@ impl Func {
             Repr::Element(elem) => Some(elem.title()),
             Repr::Closure(_) => None,
             Repr::With(with) => with.0.title(),
+            Repr::Then(_) => None,
         }
     }
 
@@ -178,16 +197,22 @@ impl Func {
             Repr::Element(elem) => Some(elem.docs()),
             Repr::Closure(_) => None,
             Repr::With(with) => with.0.docs(),
+            Repr::Then(_) => None,
         }
     }
 
     /// Get details about this function's parameters if available.
+    ///
+    /// A composed function takes the arguments of the function that is called
+    /// first. The second function only ever receives a single positional
+    /// argument, which is checked when the composition is created.
     pub fn params(&self) -> Option<&'static [ParamInfo]> {
         match &self.repr {
             Repr::Native(native) => Some(&native.0.params),
             Repr::Element(elem) => Some(elem.params()),
             Repr::Closure(_) => None,
             Repr::With(with) => with.0.params(),
+            Repr::Then(then) => then.0.params(),
         }
     }
 
@@ -205,6 +230,7 @@ impl Func {
             Repr::Element(_) => Some(&CONTENT),
             Repr::Closure(_) => None,
             Repr::With(with) => with.0.returns(),
+            Repr::Then(then) => then.1.returns(),
         }
     }
 
@@ -215,6 +241,7 @@ impl Func {
             Repr::Element(elem) => elem.keywords(),
             Repr::Closure(_) => &[],
             Repr::With(with) => with.0.keywords(),
+            Repr::Then(_) => &[],
         }
     }
 
@@ -225,6 +252,7 @@ impl Func {
             Repr::Element(elem) => Some(elem.scope()),
             Repr::Closure(_) => None,
             Repr::With(with) => with.0.scope(),
+            Repr::Then(_) => None,
         }
     }
 
@@ -288,6 +316,54 @@ impl Func {
                 args.items = with.1.items.iter().cloned().chain(args.items).collect();
                 with.0.call(engine, args)
             }
+            Repr::Then(then) => {
+                let value = then.0.call(engine, args)?;
+                then.1.call(engine, [value])
+            }
+        }
+    }
+
+    /// Ensure that the function can be called with just one positional
+    /// argument, as the second function of a composition is.
+    ///
+    /// Only native and element functions are checked since the parameters of
+    /// closures and functions with pre-applied arguments are not known
+    /// upfront.
+    fn check_unary(&self) -> StrResult<()> {
+        if !matches!(self.repr, Repr::Native(_) | Repr::Element(_)) {
+            return Ok(());
+        }
+
+        let Some(params) = self.params() else { return Ok(()) };
+        let positional = params.iter().filter(|p| p.required && p.positional).count();
+        let named = params.iter().any(|p| p.required && !p.positional);
+        if positional > 1 || named {
+            bail!(
+                "function `{}` cannot be chained as it takes more than one argument",
+                self.name().unwrap_or("..")
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Hash the function such that functions that are equal have the same
+    /// hash. Pre-applied arguments and composed functions are hashed without
+    /// their spans.
+    pub(super) fn hash_structurally(&self, state: &mut impl Hasher) {
+        std::mem::discriminant(&self.repr).hash(state);
+        match &self.repr {
+            Repr::Native(_) | Repr::Element(_) | Repr::Closure(_) => {
+                self.repr.hash(state)
+            }
+            Repr::With(with) => {
+                with.0.hash_structurally(state);
+                super::hash_args_structurally(&with.1, state);
+            }
+            Repr::Then(then) => {
+                then.0.hash_structurally(state);
+                then.1.hash_structurally(state);
+            }
         }
     }
 
@@ -326,6 +402,78 @@ impl Func {
         }
     }
 
+    /// Returns the underlying function and the pre-applied arguments of a
+    /// function created with [`with`]($function.with) as a dictionary with
+    /// the keys `func` and `args`. Chains of `with` calls are unwrapped. Returns
+    /// `{none}` if the function has no pre-applied arguments.
+    ///
+    /// ```example
+    /// #let add(x, y, z) = x + y + z
+    /// #let (func, args) = add.with(1).with(2, z: 3).applied()
+    /// #(func == add) \
+    /// #args
+    /// ```
+    #[func]
+    pub fn applied(self) -> Option<Dict> {
+        let mut func = self;
+        let mut args: Option<Args> = None;
+        while let Repr::With(with) = &func.repr {
+            let (inner, applied) = &**with;
+            args = Some(match args {
+                Some(outer) => Args {
+                    span: outer.span,
+                    items: applied.items.iter().cloned().chain(outer.items).collect(),
+                },
+                None => applied.clone(),
+            });
+            func = inner.clone();
+        }
+
+        let args = args?;
+        Some(dict! { "func" => func, "args" => args })
+    }
+
+    /// Returns a new function that calls this function and then passes its
+    /// result to the given function.
+    ///
+    /// This makes it possible to build pipelines from small functions, for
+    /// example in show rules.
+    ///
+    /// ```example
+    /// #show "Typst": emph.then(text.with(blue))
+    /// Typst is great.
+    /// ```
+    #[func]
+    pub fn then(
+        self,
+        /// The function to pass the result of this function to.
+        next: Func,
+    ) -> StrResult<Func> {
+        next.check_unary()?;
+        let span = self.span;
+        Ok(Self { repr: Repr::Then(Arc::new((self, next))), span })
+    }
+
+    /// Returns a new function that first calls the given function and then
+    /// passes its result to this function. This is the reverse of
+    /// [`then`]($function.then).
+    ///
+    /// ```example
+    /// #let double = x => 2 * x
+    /// #let inc = x => x + 1
+    /// #double.compose(inc)(3)
+    /// ```
+    #[func]
+    pub fn compose(
+        self,
+        /// The function to call first.
+        inner: Func,
+    ) -> StrResult<Func> {
+        self.check_unary()?;
+        let span = self.span;
+        Ok(Self { repr: Repr::Then(Arc::new((inner, self))), span })
+    }
+
     /// Returns a selector that filters for elements belonging to this function
     /// whose fields have the values of the given arguments.
     #[func]
@@ -371,9 +519,17 @@ impl Debug for Func {
 
 impl repr::Repr for Func {
     fn repr(&self) -> EcoString {
-        match self.name() {
-            Some(name) => name.into(),
-            None => "(..) => ..".into(),
+        match (&self.repr, self.name()) {
+            (Repr::Then(then), _) => {
+                let first = then.0.repr();
+                let first = match (&then.0.repr, then.0.name()) {
+                    (Repr::Then(_), _) | (_, Some(_)) => first,
+                    (_, None) => eco_format!("({first})"),
+                };
+                eco_format!("{first}.then({})", then.1.repr())
+            }
+            (_, Some(name)) => name.into(),
+            (_, None) => "(..) => ..".into(),
         }
     }
 }
@@ -472,10 +628,13 @@ pub struct ParamInfo {
 pub struct Closure {
     /// The closure's syntax node. Must be castable to `ast::Closure`.
     pub node: SyntaxNode,
-    /// Default values of named parameters.
+    /// Default values of parameters with defaults, in order.
     pub defaults: Vec<Value>,
     /// Captured values from outer scopes.
     pub captured: Scope,
+    /// The name the closure was bound to with `let f = (..) => ..`, under
+    /// which it can call itself.
+    pub binding: Option<EcoString>,
 }
 
 impl Closure {
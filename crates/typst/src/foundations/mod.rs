@@ -24,6 +24,8 @@ mod methods;
 mod module;
 mod none;
 mod plugin;
+mod range;
+mod rng;
 mod scope;
 mod selector;
 mod str;
@@ -51,7 +53,9 @@ pub use self::methods::*;
 pub use self::module::*;
 pub use self::none::*;
 pub use self::plugin::*;
+pub use self::range::*;
 pub use self::repr::Repr;
+pub use self::rng::*;
 pub use self::scope::*;
 pub use self::selector::*;
 pub use self::str::*;
@@ -68,11 +72,15 @@ pub use {
     once_cell::sync::Lazy,
 };
 
+use std::hash::{Hash, Hasher};
+
 use ecow::EcoString;
+use siphasher::sip128::SipHasher13;
 
 use crate::diag::{bail, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::eval::EvalMode;
+use crate::layout::Rel;
 use crate::syntax::Spanned;
 
 /// Foundational types and functions.
@@ -93,6 +101,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define_type::<Bytes>();
     global.define_type::<Content>();
     global.define_type::<Array>();
+    global.define_type::<Range>();
     global.define_type::<Dict>();
     global.define_type::<Func>();
     global.define_type::<Args>();
@@ -104,11 +113,16 @@ pub(super) fn define(global: &mut Scope) {
     global.define_type::<Duration>();
     global.define_type::<Version>();
     global.define_type::<Plugin>();
+    global.define_type::<Rng>();
     global.define_func::<repr::repr>();
+    global.define_func::<hash>();
     global.define_func::<panic>();
     global.define_func::<assert>();
     global.define_func::<eval>();
+    global.define_func::<target>();
     global.define_func::<style>();
+    global.define_func::<debug_styles>();
+    global.define_func::<scoped>();
     global.define_module(calc::module());
     global.define_module(sys::module());
 }
@@ -241,6 +255,103 @@ impl assert {
     }
 }
 
+/// Computes a hash of a value.
+///
+/// Values that are equal always have the same hash. In particular, content
+/// is hashed by its elements and their fields, so two pieces of content with
+/// the same structure have the same hash no matter where in the source code
+/// they were created. The hash can thus be used to deduplicate or cache
+/// values, for instance as a key in a [dictionary]($dictionary).
+///
+/// The hash is the same in every compilation, but may change between Typst
+/// versions.
+///
+/// Closures, styles, and modules are only equal to themselves, so their hash
+/// depends on where in the source code they were created.
+///
+/// # Example
+/// ```example
+/// #let unique = (:)
+/// #for item in ([A], [*B*], [A]) {
+///   unique.insert(str(hash(item)), item)
+/// }
+/// #unique.values().join[, ]
+/// ```
+#[func]
+pub fn hash(
+    /// The value to hash.
+    value: Value,
+) -> i64 {
+    let mut state = SipHasher13::new();
+    hash_structurally(&value, &mut state);
+    state.finish() as i64
+}
+
+/// Hash a value such that values that are equal have the same hash.
+fn hash_structurally(value: &Value, state: &mut impl Hasher) {
+    match value {
+        // Integers and floats compare equal if they have the same value.
+        Value::Int(v) => hash_structurally(&Value::Float(*v as f64), state),
+        Value::Float(v) if *v == 0.0 => Value::Float(0.0).hash(state),
+        // Lengths and ratios compare equal to relative lengths.
+        Value::Length(v) => Value::Relative(Rel::from(*v)).hash(state),
+        Value::Ratio(v) => Value::Relative(Rel::from(*v)).hash(state),
+        // Types compare equal to their old string names.
+        Value::Type(ty) => Value::Str(ty.compat_name().into()).hash(state),
+        Value::Array(array) => {
+            std::mem::discriminant(value).hash(state);
+            array.len().hash(state);
+            for item in array.iter() {
+                hash_structurally(item, state);
+            }
+        }
+        // Ranges compare equal to arrays with the same items.
+        Value::Range(range) => {
+            std::mem::discriminant(&Value::Array(Array::new())).hash(state);
+            (range.len() as usize).hash(state);
+            for item in range.iter() {
+                hash_structurally(&Value::Int(item), state);
+            }
+        }
+        // Dictionaries are equal regardless of the order of their entries.
+        Value::Dict(dict) => {
+            std::mem::discriminant(value).hash(state);
+            dict.len().hash(state);
+            let mut sum = 0_u64;
+            for (key, value) in dict.iter() {
+                let mut entry = SipHasher13::new();
+                key.hash(&mut entry);
+                hash_structurally(value, &mut entry);
+                sum = sum.wrapping_add(entry.finish());
+            }
+            sum.hash(state);
+        }
+        // Spans, locations, and labels are not part of the equality of
+        // content.
+        Value::Content(content) => {
+            std::mem::discriminant(value).hash(state);
+            content.elem().hash(state);
+            hash_structurally(&Value::Dict(content.fields()), state);
+        }
+        // Arguments are equal if their values are, regardless of their spans.
+        Value::Args(args) => {
+            std::mem::discriminant(value).hash(state);
+            hash_args_structurally(args, state);
+        }
+        Value::Func(func) => {
+            std::mem::discriminant(value).hash(state);
+            func.hash_structurally(state);
+        }
+        _ => value.hash(state),
+    }
+}
+
+/// Hash arguments such that arguments that are equal have the same hash.
+fn hash_args_structurally(args: &Args, state: &mut impl Hasher) {
+    hash_structurally(&Value::Array(args.to_pos()), state);
+    hash_structurally(&Value::Dict(args.to_named()), state);
+}
+
 /// Evaluates a string as Typst code.
 ///
 /// This function should only be used as a last resort.
@@ -293,3 +404,33 @@ pub fn eval(
     }
     crate::eval::eval_string(engine.world, &text, span, mode, scope)
 }
+
+/// Returns the current export target.
+///
+/// This allows to produce different variants of a document from the same
+/// source, for instance to use a different font or page size for a preview
+/// image than for the final PDF. The target is chosen by the application that
+/// compiles the document. The CLI picks it based on the output format.
+///
+/// ```example
+/// #set page(fill: luma(230)) if target() == "raster"
+///
+/// Compiled for #target() output.
+/// ```
+#[func]
+pub fn target(
+    /// The engine.
+    engine: &mut Engine,
+) -> Target {
+    engine.world.library().target
+}
+
+/// The kind of output a document is compiled for.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum Target {
+    /// Page-based vector output, like PDF or SVG.
+    #[default]
+    Paged,
+    /// Raster images, like PNG.
+    Raster,
+}
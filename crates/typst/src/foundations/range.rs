@@ -0,0 +1,366 @@
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroI64;
+
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{SourceResult, StrResult};
+use crate::foundations::{func, scope, ty, Args, Array, IntoValue, Repr, Value};
+
+/// A sequence of evenly spaced integers.
+///
+/// A range describes the integers from a start (inclusive) to an end
+/// (exclusive) with a fixed step between them. Unlike an array, a range does
+/// not store its items. Thus, you can loop over huge ranges without running
+/// out of memory.
+///
+/// Ranges are accepted wherever an array is expected and can be converted to
+/// an array explicitly with the [`array`]($array) function. Besides the
+/// methods listed below, all [array methods]($array) can be called on a range,
+/// which converts it into an array first.
+///
+/// # Example
+/// ```example
+/// #range(5) \
+/// #range(2, 5).len() \
+/// #range(20, step: 4).rev() \
+/// #array(range(5, 2, step: -1))
+///
+/// #for i in range(3) [
+///   Item #i.
+/// ]
+/// ```
+#[ty(scope)]
+#[derive(Copy, Clone)]
+pub struct Range {
+    /// The first item.
+    start: i64,
+    /// The number of items.
+    len: u64,
+    /// The distance between consecutive items.
+    step: NonZeroI64,
+}
+
+impl Range {
+    /// Create a range from its bounds and step.
+    pub fn new(start: i64, end: i64, step: NonZeroI64) -> Self {
+        let step_abs = i128::from(step.get()).abs();
+        let distance = if step.get() > 0 {
+            i128::from(end) - i128::from(start)
+        } else {
+            i128::from(start) - i128::from(end)
+        };
+
+        let len = if distance > 0 { (distance + step_abs - 1) / step_abs } else { 0 };
+        Self { start, len: len as u64, step }
+    }
+
+    /// Whether the range is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The item at the given index, which must be in bounds.
+    fn nth(&self, index: u64) -> i64 {
+        // Items within bounds always fit into an `i64`.
+        (i128::from(self.start) + i128::from(index) * i128::from(self.step.get())) as i64
+    }
+
+    /// The exclusive end of the range.
+    fn end(&self) -> i128 {
+        i128::from(self.start) + i128::from(self.len) * i128::from(self.step.get())
+    }
+
+    /// Resolve an index, if it is within bounds.
+    ///
+    /// `index == len` is considered in bounds if and only if `end_ok` is true.
+    fn locate_opt(&self, index: i64, end_ok: bool) -> Option<u64> {
+        let wrapped = if index >= 0 {
+            i128::from(index)
+        } else {
+            i128::from(self.len) + i128::from(index)
+        };
+
+        u64::try_from(wrapped).ok().filter(|&v| v < self.len + end_ok as u64)
+    }
+
+    /// Resolve an index or throw an out of bounds error.
+    fn locate(&self, index: i64, end_ok: bool) -> StrResult<u64> {
+        self.locate_opt(index, end_ok)
+            .ok_or_else(|| out_of_bounds(index, self.len))
+    }
+
+    /// Iterate over the items.
+    pub fn iter(&self) -> RangeIter {
+        RangeIter { range: *self, index: 0 }
+    }
+
+    /// Feed the items into a hasher exactly like an array with the same items.
+    pub fn hash_as_array<H: Hasher>(&self, state: &mut H) {
+        // Arrays hash as a slice of values: their length and then each item.
+        state.write_usize(self.len as usize);
+        for item in self.iter() {
+            Value::Int(item).hash(state);
+        }
+    }
+
+    /// Collect the items into an array.
+    pub fn to_array(&self) -> Array {
+        self.iter().map(IntoValue::into_value).collect()
+    }
+}
+
+#[scope]
+impl Range {
+    /// Creates a range of integers.
+    ///
+    /// If you pass just one positional parameter, it is interpreted as the
+    /// `end` of the range. If you pass two, they describe the `start` and `end`
+    /// of the range.
+    ///
+    /// ```example
+    /// #range(5) \
+    /// #range(2, 5) \
+    /// #range(20, step: 4) \
+    /// #range(21, step: 4) \
+    /// #range(5, 2, step: -1)
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The real arguments (the other arguments are just for the docs, this
+        /// function is a bit involved, so we parse the arguments manually).
+        args: &mut Args,
+        /// The start of the range (inclusive).
+        #[external]
+        #[default]
+        start: i64,
+        /// The end of the range (exclusive).
+        #[external]
+        end: i64,
+        /// The distance between the generated numbers.
+        #[named]
+        #[default(NonZeroI64::new(1).unwrap())]
+        step: NonZeroI64,
+    ) -> SourceResult<Range> {
+        let first = args.expect::<i64>("end")?;
+        let (start, end) = match args.eat::<i64>()? {
+            Some(second) => (first, second),
+            None => (0, first),
+        };
+
+        Ok(Self::new(start, end, step))
+    }
+
+    /// The number of integers in the range.
+    #[func(title = "Length")]
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns the first integer in the range. Fails with an error if the range
+    /// is empty.
+    #[func]
+    pub fn first(&self) -> StrResult<i64> {
+        if self.is_empty() {
+            return Err(range_is_empty());
+        }
+        Ok(self.start)
+    }
+
+    /// Returns the last integer in the range. Fails with an error if the range
+    /// is empty.
+    #[func]
+    pub fn last(&self) -> StrResult<i64> {
+        if self.is_empty() {
+            return Err(range_is_empty());
+        }
+        Ok(self.nth(self.len - 1))
+    }
+
+    /// Returns the integer at the specified index in the range. Returns the
+    /// default value if the index is out of bounds or fails with an error if
+    /// no default value was specified.
+    #[func]
+    pub fn at(
+        &self,
+        /// The index at which to retrieve the integer. If negative, indexes
+        /// from the back.
+        index: i64,
+        /// A default value to return if the index is out of bounds.
+        #[named]
+        default: Option<Value>,
+    ) -> StrResult<Value> {
+        self.locate_opt(index, false)
+            .map(|i| self.nth(i).into_value())
+            .or(default)
+            .ok_or_else(|| out_of_bounds_no_default(index, self.len))
+    }
+
+    /// Extracts a subrange. Fails with an error if the start or end index is
+    /// out of bounds.
+    ///
+    /// ```example
+    /// #range(10).slice(2, 6) \
+    /// #range(0, 100, step: 10).slice(-3)
+    /// ```
+    #[func]
+    pub fn slice(
+        &self,
+        /// The start index (inclusive). If negative, indexes from the back.
+        start: i64,
+        /// The end index (exclusive). If omitted, the whole subrange until the
+        /// end of the range is extracted. If negative, indexes from the back.
+        #[default]
+        end: Option<i64>,
+        /// The number of integers to extract. This is equivalent to passing
+        /// `start + count` as the `end` position. Mutually exclusive with
+        /// `end`.
+        #[named]
+        count: Option<i64>,
+    ) -> StrResult<Range> {
+        let mut end = end;
+        if end.is_none() {
+            end = count.map(|c: i64| start.saturating_add(c));
+        }
+        let start = self.locate(start, true)?;
+        let end = match end {
+            Some(end) => self.locate(end, true)?.max(start),
+            None => self.len,
+        };
+
+        let first = if start < self.len { self.nth(start) } else { self.start };
+        Ok(Self { start: first, len: end - start, step: self.step })
+    }
+
+    /// Whether the range contains the specified value.
+    ///
+    /// This method also has dedicated syntax: You can write `{2 in range(5)}`
+    /// instead of `{range(5).contains(2)}`.
+    #[func]
+    pub fn contains(
+        &self,
+        /// The value to search for.
+        value: Value,
+    ) -> bool {
+        let x = match value {
+            Value::Int(x) => x,
+            Value::Float(x) if x.fract() == 0.0 && x.abs() < i64::MAX as f64 => x as i64,
+            _ => return false,
+        };
+
+        let offset = i128::from(x) - i128::from(self.start);
+        let step = i128::from(self.step.get());
+        offset % step == 0 && (0..i128::from(self.len)).contains(&(offset / step))
+    }
+
+    /// Returns a range with the same integers, but in reverse order.
+    #[func(title = "Reverse")]
+    pub fn rev(&self) -> StrResult<Range> {
+        if self.len <= 1 {
+            return Ok(*self);
+        }
+
+        let step = self
+            .step
+            .get()
+            .checked_neg()
+            .and_then(NonZeroI64::new)
+            .ok_or("cannot reverse a range with a step of the minimum integer")?;
+
+        Ok(Self { start: self.nth(self.len - 1), len: self.len, step })
+    }
+}
+
+impl Debug for Range {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(&self.repr())
+    }
+}
+
+impl Repr for Range {
+    fn repr(&self) -> EcoString {
+        match self.step.get() {
+            1 => eco_format!("range({}, {})", self.start, self.end()),
+            step => eco_format!("range({}, {}, step: {step})", self.start, self.end()),
+        }
+    }
+}
+
+/// Ranges with the same items are equal, regardless of how they were created.
+impl PartialEq for Range {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len
+            && (self.is_empty() || self.start == other.start)
+            && (self.len <= 1 || self.step == other.step)
+    }
+}
+
+impl Eq for Range {}
+
+impl Hash for Range {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        if !self.is_empty() {
+            self.start.hash(state);
+        }
+        if self.len > 1 {
+            self.step.hash(state);
+        }
+    }
+}
+
+impl IntoIterator for Range {
+    type Item = i64;
+    type IntoIter = RangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the integers of a [`Range`].
+#[derive(Debug, Clone)]
+pub struct RangeIter {
+    range: Range,
+    index: u64,
+}
+
+impl Iterator for RangeIter {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.range.len {
+            return None;
+        }
+
+        let x = self.range.nth(self.index);
+        self.index += 1;
+        Some(x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = usize::try_from(self.range.len - self.index).ok();
+        (remaining.unwrap_or(usize::MAX), remaining)
+    }
+}
+
+/// The error message when the range is empty.
+#[cold]
+fn range_is_empty() -> EcoString {
+    "range is empty".into()
+}
+
+/// The out of bounds access error message.
+#[cold]
+fn out_of_bounds(index: i64, len: u64) -> EcoString {
+    eco_format!("range index out of bounds (index: {index}, len: {len})")
+}
+
+/// The out of bounds access error message when no default value was given.
+#[cold]
+fn out_of_bounds_no_default(index: i64, len: u64) -> EcoString {
+    eco_format!(
+        "range index out of bounds (index: {index}, len: {len}) \
+         and no default value was specified",
+    )
+}
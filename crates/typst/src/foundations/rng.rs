@@ -0,0 +1,130 @@
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{bail, StrResult};
+use crate::foundations::{array, cast, func, scope, ty, Array, Repr};
+
+/// A deterministic pseudo-random number generator.
+///
+/// A Typst document always produces the same output, so there is no true
+/// randomness. Instead, a generator is created from a seed and yields the same
+/// sequence of numbers for the same seed on every compilation. This keeps
+/// randomized exams or figures reproducible.
+///
+/// Since values in Typst are immutable, each method returns an array with the
+/// generator's next state and the result. Use
+/// [destructuring]($scripting/#bindings) to continue with the next state.
+///
+/// # Example
+/// ```example
+/// #let r = rng(42)
+/// #let (r, a) = r.int(1, 7)
+/// #let (r, b) = r.int(1, 7)
+/// #let (r, names) = r.shuffle(
+///   ("Anna", "Ben", "Cleo"),
+/// )
+///
+/// Rolled a #a and a #b. \
+/// Order: #names.join(", ")
+/// ```
+#[ty(scope)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// Advance the generator and produce the next 64 random bits.
+    ///
+    /// This is the SplitMix64 algorithm.
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Produce a uniformly distributed number below `bound`.
+    fn below(&mut self, bound: u64) -> u64 {
+        // Reject values from the incomplete last interval to avoid a bias
+        // towards small numbers.
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let v = self.next();
+            if v < zone {
+                return v % bound;
+            }
+        }
+    }
+}
+
+#[scope]
+impl Rng {
+    /// Creates a new generator from a seed.
+    #[func(constructor)]
+    pub fn construct(
+        /// The seed. The same seed always produces the same numbers.
+        seed: i64,
+    ) -> Rng {
+        Self(seed as u64)
+    }
+
+    /// Produces a random integer between `low` (inclusive) and `high`
+    /// (exclusive).
+    ///
+    /// Returns the next state of the generator and the integer.
+    #[func]
+    pub fn int(
+        self,
+        /// The lowest possible integer.
+        low: i64,
+        /// The integer after the highest possible one.
+        high: i64,
+    ) -> StrResult<Array> {
+        if high <= low {
+            bail!("high must be greater than low");
+        }
+
+        let mut rng = self;
+        let span = (high as i128 - low as i128) as u64;
+        let value = (low as i128 + rng.below(span) as i128) as i64;
+        Ok(array![rng, value])
+    }
+
+    /// Produces a random float between `{0.0}` (inclusive) and `{1.0}`
+    /// (exclusive).
+    ///
+    /// Returns the next state of the generator and the float.
+    #[func]
+    pub fn float(self) -> Array {
+        let mut rng = self;
+        let value = (rng.next() >> 11) as f64 / (1u64 << 53) as f64;
+        array![rng, value]
+    }
+
+    /// Shuffles the items of an array into a random order.
+    ///
+    /// Returns the next state of the generator and the shuffled array.
+    #[func]
+    pub fn shuffle(
+        self,
+        /// The array to shuffle.
+        array: Array,
+    ) -> Array {
+        let mut rng = self;
+        let mut items: Vec<_> = array.into_iter().collect();
+        for i in (1..items.len()).rev() {
+            let j = rng.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+        array![rng, items.into_iter().collect::<Array>()]
+    }
+}
+
+impl Repr for Rng {
+    fn repr(&self) -> EcoString {
+        eco_format!("rng({})", self.0 as i64)
+    }
+}
+
+cast! {
+    type Rng,
+}
@@ -140,6 +140,17 @@ impl Scope {
             .insert(name, Slot::new(value.into_value(), Kind::Normal, self.category));
     }
 
+    /// Bind a value to a name and mark it as a constant, so that shadowing
+    /// it can be diagnosed.
+    #[track_caller]
+    pub fn define_constant(&mut self, name: impl Into<EcoString>, value: impl IntoValue) {
+        let name = name.into();
+        self.define(name.clone(), value);
+        if let Some(slot) = self.map.get_mut(&name) {
+            slot.kind = Kind::Constant;
+        }
+    }
+
     /// Define a native function through a Rust type that shadows the function.
     pub fn define_func<T: NativeFunc>(&mut self) {
         let data = T::data();
@@ -158,9 +169,12 @@ impl Scope {
     }
 
     /// Define a native element.
+    ///
+    /// Elements are marked as constants because shadowing them breaks set and
+    /// show rules.
     pub fn define_elem<T: NativeElement>(&mut self) {
         let data = T::data();
-        self.define(data.name, Element::from(data));
+        self.define_constant(data.name, Element::from(data));
     }
 
     /// Define a module.
@@ -189,6 +203,11 @@ impl Scope {
             .map(|res| res.map_err(HintedString::from))
     }
 
+    /// Whether the definition is marked as a constant.
+    pub fn is_constant(&self, var: &str) -> bool {
+        self.map.get(var).is_some_and(|slot| slot.kind == Kind::Constant)
+    }
+
     /// Get the category of a definition.
     pub fn get_category(&self, var: &str) -> Option<Category> {
         self.map.get(var)?.category
@@ -247,6 +266,8 @@ enum Kind {
     Normal,
     /// A captured copy of another variable.
     Captured,
+    /// A binding whose shadowing is diagnosed.
+    Constant,
 }
 
 impl Slot {
@@ -263,7 +284,7 @@ impl Slot {
     /// Try to write to the value.
     fn write(&mut self) -> StrResult<&mut Value> {
         match self.kind {
-            Kind::Normal => Ok(&mut self.value),
+            Kind::Normal | Kind::Constant => Ok(&mut self.value),
             Kind::Captured => {
                 bail!(
                     "variables from outside the function are \
@@ -211,6 +211,18 @@ impl Selector {
 
     /// Returns a modified selector that will only match elements that occur
     /// after the first match of `start`.
+    ///
+    /// This selector can also be used in show rules, as long as the original
+    /// selector matches elements with a location (e.g. headings, figures or
+    /// labelled elements).
+    ///
+    /// ```example
+    /// #show heading.after(<appendix>): set text(blue)
+    ///
+    /// = Introduction
+    /// = Appendix <appendix>
+    /// = Sources
+    /// ```
     #[func]
     pub fn after(
         self,
@@ -409,12 +421,16 @@ impl FromValue for ShowableSelector {
                 Selector::Elem(_, _) => {}
                 Selector::Label(_) => {}
                 Selector::Regex(_) => {}
+                Selector::Before { selector, .. } | Selector::After { selector, .. } => {
+                    if matches!(&**selector, Selector::Regex(_)) {
+                        bail!("this selector cannot be used with show");
+                    }
+                    validate(selector)?;
+                }
                 Selector::Or(_)
                 | Selector::And(_)
                 | Selector::Location(_)
-                | Selector::Can(_)
-                | Selector::Before { .. }
-                | Selector::After { .. } => {
+                | Selector::Can(_) => {
                     bail!("this selector cannot be used with show")
                 }
             }
@@ -5,14 +5,15 @@ use std::hash::{Hash, Hasher};
 use std::{iter, mem, ptr};
 
 use comemo::Prehashed;
-use ecow::{eco_vec, EcoString, EcoVec};
+use ecow::{eco_format, eco_vec, EcoString, EcoVec};
 use once_cell::sync::Lazy;
 use smallvec::SmallVec;
 
-use crate::diag::{SourceResult, Trace, Tracepoint};
+use crate::diag::{bail, SourceDiagnostic, SourceResult, StrResult, Trace, Tracepoint};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, func, ty, Content, Element, Func, NativeElement, Repr, Selector, Show,
+    cast, elem, func, scope, ty, AutoValue, Content, Element, Func, IntoValue,
+    NativeElement, Repr, Selector, Show, Str,
 };
 use crate::syntax::Span;
 use crate::text::{FontFamily, FontList, TextElem};
@@ -61,6 +62,148 @@ impl Show for StyleElem {
     }
 }
 
+/// Reports which set rules affect an element at this point in the document.
+///
+/// For each property of the element that is set in the current style context,
+/// a warning is emitted that points to the set rule and shows the value. This
+/// helps to find out where a surprising style comes from, for instance in deep
+/// template stacks. Properties set by the built-in appearance of other
+/// elements, like the larger text size of headings, are reported at the call
+/// itself. The function displays nothing.
+///
+/// ```example
+/// #set text(size: 12pt)
+/// #show heading: set text(fill: blue)
+///
+/// = Title #debug-styles(text)
+/// ```
+#[func]
+pub fn debug_styles(
+    /// The element whose properties to inspect.
+    elem: Element,
+    /// The field to inspect. If omitted, all fields are inspected.
+    #[default]
+    field: Option<Str>,
+) -> StrResult<Content> {
+    let id = match &field {
+        Some(field) => match elem.field_id(field) {
+            Some(id) => Some(id),
+            None => bail!("element {} has no field \"{field}\"", elem.name()),
+        },
+        None => None,
+    };
+
+    Ok(DebugStylesElem::new(elem, id).pack())
+}
+
+/// Executes a style inspection.
+#[elem(Show)]
+struct DebugStylesElem {
+    /// The element whose properties to inspect.
+    #[required]
+    elem: Element,
+
+    /// The ID of the field to inspect.
+    #[required]
+    id: Option<u8>,
+}
+
+impl Show for DebugStylesElem {
+    #[tracing::instrument(name = "DebugStylesElem::show", skip_all)]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let elem = *self.elem();
+        let mut seen = vec![];
+        let mut depth = 0_usize;
+        for entry in styles.entries() {
+            let property = match entry {
+                Style::Property(property) => property,
+                Style::Barrier => {
+                    depth += 1;
+                    continue;
+                }
+                Style::Scope => {
+                    depth = depth.saturating_sub(1);
+                    continue;
+                }
+                Style::Recipe(_) => continue,
+            };
+
+            if !property.is_of(elem) || self.id().is_some_and(|id| id != property.id) {
+                continue;
+            }
+
+            let name = elem.field_name(property.id).unwrap_or_default();
+            let (span, origin) = match property.span.filter(|span| !span.is_detached()) {
+                Some(span) => (span, "here"),
+                None => (self.span(), "by a built-in style"),
+            };
+
+            let mut warning = SourceDiagnostic::warning(
+                span,
+                eco_format!(
+                    "{}.{name} is set to {} {origin}",
+                    elem.name(),
+                    property.value.repr()
+                ),
+            );
+
+            if depth > 0 {
+                warning.hint("this set rule is hidden by a style barrier");
+            } else if seen.contains(&property.id) {
+                warning.hint("an inner set rule takes precedence");
+            } else {
+                seen.push(property.id);
+            }
+
+            engine.tracer.warn(warning);
+        }
+
+        Ok(Content::empty())
+    }
+}
+
+/// Limits the reach of set rules to parts of a piece of content.
+///
+/// Set rules in a function also apply to the content that was passed into it.
+/// When a component wraps its output in `scoped` and the content it received
+/// in [`scoped.barrier`]($scoped.barrier), the received content is shielded
+/// from the component's set rules and keeps the styles of the place where the
+/// component is used.
+///
+/// ```example
+/// #let card(title, body) = scoped({
+///   set text(fill: red)
+///   strong(title)
+///   [: ]
+///   scoped.barrier(body)
+/// })
+///
+/// #card[Note][The body stays black.]
+/// ```
+#[func(scope)]
+pub fn scoped(
+    /// The content whose set rules should not reach past barriers within it.
+    body: Content,
+) -> Content {
+    body.styled(Style::Scope)
+}
+
+#[scope]
+impl scoped {
+    /// Shields content from the set rules of the innermost enclosing
+    /// [`scoped`]($scoped) call.
+    ///
+    /// If there is no enclosing `scoped` call, the content is shielded from
+    /// all set rules and uses the default styles.
+    #[func]
+    pub fn barrier(
+        /// The content to shield.
+        body: Content,
+    ) -> Content {
+        body.styled(Style::Barrier)
+    }
+}
+
 /// A list of style properties.
 #[ty]
 #[derive(Default, PartialEq, Clone, Hash)]
@@ -126,6 +269,7 @@ impl Styles {
         self.0.iter().find_map(|entry| match &**entry {
             Style::Property(property) => property.is_of(elem).then_some(property.span),
             Style::Recipe(recipe) => recipe.is_of(elem).then_some(Some(recipe.span)),
+            _ => None,
         })
     }
 
@@ -166,6 +310,11 @@ pub enum Style {
     Property(Property),
     /// A show rule recipe.
     Recipe(Recipe),
+    /// Marks the outer end of the properties hidden by a barrier.
+    Scope,
+    /// Hides the properties between itself and the enclosing scope marker
+    /// from the content within.
+    Barrier,
 }
 
 impl Style {
@@ -191,6 +340,8 @@ impl Debug for Style {
         match self {
             Self::Property(property) => property.fmt(f),
             Self::Recipe(recipe) => recipe.fmt(f),
+            Self::Scope => f.write_str("Scope"),
+            Self::Barrier => f.write_str("Barrier"),
         }
     }
 }
@@ -224,7 +375,7 @@ impl Property {
     /// Create a new property from a key-value pair.
     pub fn new<T>(elem: Element, id: u8, value: T) -> Self
     where
-        T: Debug + Clone + Hash + Send + Sync + 'static,
+        T: Debug + Clone + Hash + IntoValue + Send + Sync + 'static,
     {
         Self { elem, id, value: Block::new(value), span: None }
     }
@@ -271,6 +422,11 @@ impl Block {
     fn downcast<T: 'static>(&self) -> Option<&T> {
         self.0.as_any().downcast_ref()
     }
+
+    /// The Typst representation of the stored value.
+    fn repr(&self) -> EcoString {
+        self.0.dyn_repr()
+    }
 }
 
 impl Debug for Block {
@@ -301,9 +457,12 @@ trait Blockable: Debug + Send + Sync + 'static {
 
     /// Equivalent to [`Clone`] for the block.
     fn dyn_clone(&self) -> Block;
+
+    /// Equivalent to [`Repr`] of the value for the block.
+    fn dyn_repr(&self) -> EcoString;
 }
 
-impl<T: Debug + Clone + Hash + Send + Sync + 'static> Blockable for T {
+impl<T: Debug + Clone + Hash + IntoValue + Send + Sync + 'static> Blockable for T {
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -322,6 +481,10 @@ impl<T: Debug + Clone + Hash + Send + Sync + 'static> Blockable for T {
     fn dyn_clone(&self) -> Block {
         Block(Box::new(self.clone()))
     }
+
+    fn dyn_repr(&self) -> EcoString {
+        self.clone().into_value().repr()
+    }
 }
 
 impl Hash for dyn Blockable {
@@ -352,9 +515,15 @@ impl Recipe {
 
     /// Whether the recipe is applicable to the target.
     pub fn applicable(&self, target: &Content) -> bool {
-        self.selector
-            .as_ref()
-            .map_or(false, |selector| selector.matches(target))
+        match &self.selector {
+            // Whether the position constraint holds is only known once the
+            // target is located, so this just checks the inner selector.
+            Some(
+                Selector::Before { selector, .. } | Selector::After { selector, .. },
+            ) => selector.matches(target),
+            Some(selector) => selector.matches(target),
+            None => false,
+        }
     }
 
     /// Apply the recipe to the given content.
@@ -370,6 +539,7 @@ impl Recipe {
                 result?.display()
             }
             Transformation::Style(styles) => content.styled_with_map(styles.clone()),
+            Transformation::Reset => content,
         };
         if content.span().is_detached() {
             content = content.spanned(self.span);
@@ -398,6 +568,8 @@ pub enum Transformation {
     Func(Func),
     /// Apply styles to the content.
     Style(Styles),
+    /// Revoke all show rules for the match that were defined before this one.
+    Reset,
 }
 
 impl Debug for Transformation {
@@ -406,12 +578,14 @@ impl Debug for Transformation {
             Self::Content(content) => content.fmt(f),
             Self::Func(func) => func.fmt(f),
             Self::Style(styles) => styles.fmt(f),
+            Self::Reset => f.write_str("auto"),
         }
     }
 }
 
 cast! {
     Transformation,
+    _: AutoValue => Self::Reset,
     content: Content => Self::Content(content),
     func: Func => Self::Func(func),
 }
@@ -548,22 +722,16 @@ impl<'a> StyleChain<'a> {
         id: u8,
         inherent: Option<&'a T>,
     ) -> impl Iterator<Item = &'a T> {
-        inherent.into_iter().chain(
-            self.entries()
-                .filter_map(Style::property)
-                .filter(move |property| property.is(func, id))
-                .map(|property| &property.value)
-                .map(move |value| {
-                    value.downcast().unwrap_or_else(|| {
-                        panic!(
-                            "attempted to read a value of a different type than was written {}.{}: {:?}",
-                            func.name(),
-                            func.field_name(id).unwrap(),
-                            value
-                        )
-                    })
-                }),
-        )
+        inherent.into_iter().chain(self.matching(func, id).map(move |value| {
+            value.downcast().unwrap_or_else(|| {
+                panic!(
+                    "attempted to read a value of a different type than was written {}.{}: {:?}",
+                    func.name(),
+                    func.field_name(id).unwrap(),
+                    value
+                )
+            })
+        }))
     }
 
     /// Convert to a style map.
@@ -580,6 +748,17 @@ impl<'a> StyleChain<'a> {
         Entries { inner: [].as_slice().iter(), links: self.links() }
     }
 
+    /// Iterate over the raw values of the given property in the chain.
+    fn matching(self, elem: Element, id: u8) -> Matching<'a> {
+        Matching {
+            inner: [].as_slice().iter(),
+            links: self.links(),
+            elem,
+            id,
+            depth: 0,
+        }
+    }
+
     /// Iterate over the links of the chain.
     fn links(self) -> Links<'a> {
         Links(Some(self))
@@ -645,6 +824,42 @@ impl<'a> Iterator for Entries<'a> {
     }
 }
 
+/// An iterator over the values of one property in a style chain that skips
+/// entries hidden by a barrier.
+///
+/// This walks the links directly and tracks barriers in the same pass that
+/// matches the property.
+struct Matching<'a> {
+    inner: std::slice::Iter<'a, Prehashed<Style>>,
+    links: Links<'a>,
+    elem: Element,
+    id: u8,
+    depth: usize,
+}
+
+impl<'a> Iterator for Matching<'a> {
+    type Item = &'a Block;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            while let Some(entry) = self.inner.next_back() {
+                match &**entry {
+                    Style::Property(property) => {
+                        if self.depth == 0 && property.is(self.elem, self.id) {
+                            return Some(&property.value);
+                        }
+                    }
+                    Style::Barrier => self.depth += 1,
+                    Style::Scope => self.depth = self.depth.saturating_sub(1),
+                    Style::Recipe(_) => {}
+                }
+            }
+
+            self.inner = self.links.next()?.iter();
+        }
+    }
+}
+
 /// An iterator over the links of a style chain.
 struct Links<'a>(Option<StyleChain<'a>>);
 
@@ -14,7 +14,7 @@ use crate::eval::ops;
 use crate::foundations::{
     fields, repr, Args, Array, AutoValue, Bytes, CastInfo, Content, Datetime, Dict,
     Duration, FromValue, Func, IntoValue, Label, Module, NativeElement, NativeType,
-    NoneValue, Plugin, Reflect, Repr, Scope, Str, Styles, Type, Version,
+    NoneValue, Plugin, Range, Reflect, Repr, Scope, Str, Styles, Type, Version,
 };
 use crate::layout::{Abs, Angle, Em, Fr, Length, Ratio, Rel};
 use crate::symbols::Symbol;
@@ -72,6 +72,8 @@ pub enum Value {
     Styles(Styles),
     /// An array of values: `(1, "hi", 12cm)`.
     Array(Array),
+    /// A sequence of integers: `range(5)`.
+    Range(Range),
     /// A dictionary value: `(a: 1, b: "hi")`.
     Dict(Dict),
     /// An executable function.
@@ -139,6 +141,7 @@ impl Value {
             Self::Content(_) => Type::of::<Content>(),
             Self::Styles(_) => Type::of::<Styles>(),
             Self::Array(_) => Type::of::<Array>(),
+            Self::Range(_) => Type::of::<Range>(),
             Self::Dict(_) => Type::of::<Dict>(),
             Self::Func(_) => Type::of::<Func>(),
             Self::Args(_) => Type::of::<Args>(),
@@ -168,6 +171,27 @@ impl Value {
         }
     }
 
+    /// The names of the fields that can be accessed on this value.
+    pub fn field_names(&self) -> Vec<EcoString> {
+        let scope = |scope: Option<&Scope>| {
+            scope
+                .into_iter()
+                .flat_map(|scope| scope.iter().map(|(name, _)| name.clone()))
+                .collect()
+        };
+
+        match self {
+            Self::Dict(dict) => dict.iter().map(|(key, _)| key.clone().into()).collect(),
+            Self::Content(content) => {
+                content.fields().iter().map(|(key, _)| key.clone().into()).collect()
+            }
+            Self::Type(ty) => scope(Some(ty.scope())),
+            Self::Func(func) => scope(func.scope()),
+            Self::Module(module) => scope(Some(module.scope())),
+            _ => fields::fields_on(self.ty()).iter().map(|&name| name.into()).collect(),
+        }
+    }
+
     /// The associated scope, if this is a function, type, or module.
     pub fn scope(&self) -> Option<&Scope> {
         match self {
@@ -251,6 +275,7 @@ impl Debug for Value {
             Self::Content(v) => Debug::fmt(v, f),
             Self::Styles(v) => Debug::fmt(v, f),
             Self::Array(v) => Debug::fmt(v, f),
+            Self::Range(v) => Debug::fmt(v, f),
             Self::Dict(v) => Debug::fmt(v, f),
             Self::Func(v) => Debug::fmt(v, f),
             Self::Args(v) => Debug::fmt(v, f),
@@ -288,6 +313,7 @@ impl Repr for Value {
             Self::Content(v) => v.repr(),
             Self::Styles(v) => v.repr(),
             Self::Array(v) => v.repr(),
+            Self::Range(v) => v.repr(),
             Self::Dict(v) => v.repr(),
             Self::Func(v) => v.repr(),
             Self::Args(v) => v.repr(),
@@ -313,7 +339,14 @@ impl PartialOrd for Value {
 
 impl Hash for Value {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        std::mem::discriminant(self).hash(state);
+        // Ranges compare equal to arrays with the same items, so they must
+        // hash like them, too.
+        match self {
+            Self::Range(_) => std::mem::discriminant(&Self::Array(Array::new())),
+            _ => std::mem::discriminant(self),
+        }
+        .hash(state);
+
         match self {
             Self::None => {}
             Self::Auto => {}
@@ -338,6 +371,7 @@ impl Hash for Value {
             Self::Datetime(v) => v.hash(state),
             Self::Duration(v) => v.hash(state),
             Self::Array(v) => v.hash(state),
+            Self::Range(v) => v.hash_as_array(state),
             Self::Dict(v) => v.hash(state),
             Self::Func(v) => v.hash(state),
             Self::Args(v) => v.hash(state),
@@ -364,6 +398,7 @@ impl Serialize for Value {
             Self::Symbol(v) => v.serialize(serializer),
             Self::Content(v) => v.serialize(serializer),
             Self::Array(v) => v.serialize(serializer),
+            Self::Range(v) => v.to_array().serialize(serializer),
             Self::Dict(v) => v.serialize(serializer),
 
             // Fall back to repr() for other things.
@@ -656,7 +691,8 @@ primitive! { Content: "content",
     Str(v) => TextElem::packed(v)
 }
 primitive! { Styles: "styles", Styles }
-primitive! { Array: "array", Array }
+primitive! { Array: "array", Array, Range(v) => v.to_array() }
+primitive! { Range: "range", Range }
 primitive! { Dict: "dictionary", Dict }
 primitive! {
     Func: "function",
@@ -670,14 +706,28 @@ primitive! { Plugin: "plugin", Plugin }
 
 #[cfg(test)]
 mod tests {
+    use std::num::NonZeroI64;
+
     use super::*;
     use crate::foundations::{array, dict};
+    use crate::util::hash128;
 
     #[track_caller]
     fn test(value: impl IntoValue, exp: &str) {
         assert_eq!(value.into_value().repr(), exp);
     }
 
+    #[test]
+    fn test_range_hashes_like_array() {
+        let range = Range::new(0, 3, NonZeroI64::new(1).unwrap());
+        assert_eq!(
+            hash128(&Value::Range(range)),
+            hash128(&Value::Array(array![0, 1, 2]))
+        );
+        let empty = Range::new(5, 2, NonZeroI64::new(1).unwrap());
+        assert_eq!(hash128(&Value::Range(empty)), hash128(&Value::Array(array![])));
+    }
+
     #[test]
     fn test_value_debug() {
         // Primitives.
@@ -12,10 +12,10 @@ use crate::util::Numeric;
 /// Separates a region into multiple equally sized columns.
 ///
 /// The `column` function allows to separate the interior of any container into
-/// multiple columns. It will not equalize the height of the columns, instead,
-/// the columns will take up the height of their container or the remaining
-/// height on the page. The columns function can break across pages if
-/// necessary.
+/// multiple columns. By default, it will not equalize the height of the
+/// columns, instead, the columns will take up the height of their container or
+/// the remaining height on the page. The columns function can break across
+/// pages if necessary.
 ///
 /// If you need to insert columns across your whole document, you can use the
 /// [`{page}` function's `columns` parameter]($page.columns) instead.
@@ -52,6 +52,20 @@ pub struct ColumnsElem {
     #[default(Ratio::new(0.04).into())]
     pub gutter: Rel<Length>,
 
+    /// Whether to balance the columns.
+    ///
+    /// If enabled, the columns in the last region (typically, on the last
+    /// page) are shortened such that they end at roughly the same height
+    /// instead of the first columns being filled completely.
+    ///
+    /// ```example
+    /// #columns(3, balance: true)[
+    ///   #lorem(30)
+    /// ]
+    /// ```
+    #[default(false)]
+    pub balance: bool,
+
     /// The content that should be layouted into the columns.
     #[required]
     pub body: Content,
@@ -78,24 +92,66 @@ impl Layout for ColumnsElem {
         let gutter = self.gutter(styles).relative_to(regions.base().x);
         let width = (regions.size.x - gutter * (columns - 1) as f64) / columns as f64;
 
-        let backlog: Vec<_> = std::iter::once(&regions.size.y)
+        // The heights of all columns, one after another.
+        let mut heights: Vec<_> = std::iter::once(&regions.size.y)
             .chain(regions.backlog)
             .flat_map(|&height| std::iter::repeat(height).take(columns))
-            .skip(1)
             .collect();
 
-        // Create the pod regions.
-        let pod = Regions {
-            size: Size::new(width, regions.size.y),
-            full: regions.full,
-            backlog: &backlog,
-            last: regions.last,
-            expand: Axes::new(true, regions.expand.y),
-            root: regions.root,
-        };
+        // Balancing lays out the body several times. Each attempt must start
+        // from the same locator state, so that the elements in the body and
+        // after it get the same locations as without balancing.
+        let checkpoint = self.balance(styles).then(|| engine.locator.clone());
 
         // Layout the children.
-        let mut frames = body.layout(engine, styles, pod)?.into_iter();
+        let pod = column_regions(regions, width, &heights);
+        let mut fragment = body.layout(engine, styles, pod)?;
+
+        // Shorten the columns in the last region as far as possible without
+        // pushing content into another region. If there is no region to push
+        // into and the columns expand, overflow can't be detected.
+        let start = fragment.len().saturating_sub(1) / columns * columns;
+        if let Some(last) = regions.last {
+            heights.resize(heights.len().max(start + columns), last);
+        }
+
+        let mut hi = fragment
+            .iter()
+            .skip(start)
+            .map(Frame::height)
+            .fold(Abs::zero(), Abs::max);
+        let balance = hi.is_finite()
+            && heights.len() >= start + columns
+            && (regions.last.is_some() || !regions.expand.y);
+        if let Some(checkpoint) = checkpoint.filter(|_| balance) {
+            let mut settled = engine.locator.clone();
+            let mut lo = Abs::zero();
+            for _ in 0..BALANCE_ITERATIONS {
+                if hi - lo < Abs::pt(0.5) {
+                    break;
+                }
+
+                let mid = (lo + hi) / 2.0;
+                heights[start..start + columns].fill(mid);
+                let pod = column_regions(regions, width, &heights);
+                *engine.locator = checkpoint.clone();
+                let attempt = body.layout(engine, styles, pod)?;
+                if attempt.len() <= start + columns
+                    && attempt.iter().skip(start).all(|frame| mid.fits(frame.height()))
+                {
+                    fragment = attempt;
+                    settled = engine.locator.clone();
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+            }
+
+            // Continue after the attempt that was kept.
+            *engine.locator = settled;
+        }
+
+        let mut frames = fragment.into_iter();
         let mut finished = vec![];
 
         let dir = TextElem::dir_in(styles);
@@ -135,6 +191,21 @@ impl Layout for ColumnsElem {
     }
 }
 
+/// Create the regions for the columns, given the height of each column.
+fn column_regions<'a>(regions: Regions, width: Abs, heights: &'a [Abs]) -> Regions<'a> {
+    Regions {
+        size: Size::new(width, heights[0]),
+        full: regions.full,
+        backlog: &heights[1..],
+        last: regions.last,
+        expand: Axes::new(true, regions.expand.y),
+        root: regions.root,
+    }
+}
+
+/// How often to try shorter columns when balancing them.
+const BALANCE_ITERATIONS: usize = 12;
+
 /// Forces a column break.
 ///
 /// The function will behave like a [page break]($pagebreak) when used in a
@@ -1,12 +1,16 @@
-use crate::diag::SourceResult;
+use ecow::EcoString;
+
+use crate::diag::{bail, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, AutoValue, Content, NativeElement, Resolve, Smart, StyleChain, Value,
+    cast, elem, AutoValue, Cast, Content, NativeElement, Resolve, Smart, StyleChain,
+    Value,
 };
 use crate::layout::{
-    Abs, Axes, Corners, Em, Fr, Fragment, FrameKind, Layout, Length, Ratio, Regions, Rel,
-    Sides, Size, Spacing, VElem,
+    Abs, Axes, Corners, Em, Fr, Fragment, Frame, FrameKind, Layout, Length, Ratio,
+    Regions, Rel, Sides, Size, Spacing, Transform, VElem,
 };
+use crate::text::TextElem;
 use crate::util::Numeric;
 use crate::visualize::{clip_rect, Paint, Stroke};
 
@@ -45,6 +49,20 @@ pub struct BoxElem {
     /// The height of the box.
     pub height: Smart<Rel<Length>>,
 
+    /// The ratio of the box's width to its height.
+    ///
+    /// If set, the box's height is derived from its width or, if only the
+    /// height is given, the width is derived from the height. If neither is
+    /// given, the box takes up the full available width. It is an error to
+    /// specify width, height, and ratio at the same time.
+    ///
+    /// ```example
+    /// #box(ratio: 16 / 9, width: 50%, fill: luma(230))[
+    ///   Video coming soon
+    /// ]
+    /// ```
+    pub ratio: Option<f64>,
+
     /// An amount to shift the box's baseline by.
     ///
     /// ```example
@@ -104,6 +122,21 @@ pub struct BoxElem {
     #[default(false)]
     pub clip: bool,
 
+    /// What to do with content that does not fit into the box.
+    ///
+    /// This only has an effect if the box has a fixed width or height. Setting
+    /// `clip` to `{true}` is the same as an overflow of `{"clip"}`.
+    ///
+    /// ```example
+    /// #set box(width: 2.5cm, height: 1.2em, stroke: 0.5pt)
+    /// #box(overflow: "visible")[Hello from the other side] \
+    /// #box(overflow: "clip")[Hello from the other side] \
+    /// #box(overflow: "scale")[Hello from the other side] \
+    /// #box(overflow: "ellipsis")[Hello from the other side]
+    /// ```
+    #[default(Overflow::Visible)]
+    pub overflow: Overflow,
+
     /// The contents of the box.
     #[positional]
     pub body: Option<Content>,
@@ -125,23 +158,81 @@ impl Layout for BoxElem {
 
         // Resolve the sizing to a concrete size.
         let sizing = Axes::new(width, self.height(styles));
-        let expand = sizing.as_ref().map(Smart::is_custom);
-        let size = sizing
+        let mut expand = sizing.as_ref().map(Smart::is_custom);
+        let mut size = sizing
             .resolve(styles)
             .zip_map(regions.base(), |s, b| s.map(|v| v.relative_to(b)))
             .unwrap_or(regions.base());
 
+        // Derive one dimension from the other.
+        if let Some(ratio) = self.ratio(styles) {
+            if !ratio.is_finite() || ratio <= 0.0 {
+                bail!(self.span(), "aspect ratio must be positive");
+            } else if expand.x && expand.y {
+                bail!(self.span(), "cannot specify width, height, and ratio at once");
+            } else if expand.y {
+                size.x = size.y * ratio;
+            } else {
+                size.y = size.x / ratio;
+            }
+
+            if !size.is_finite() {
+                bail!(self.span(), "cannot create box with infinite size");
+            }
+
+            expand = Axes::splat(true);
+        }
+
         // Apply inset.
-        let mut body = self.body(styles).unwrap_or_default();
         let inset = self.inset(styles);
-        if inset.iter().any(|v| !v.is_zero()) {
-            body = body.padded(inset.map(|side| side.map(Length::from)));
-        }
+        let pad = |body: Content| {
+            if inset.iter().any(|v| !v.is_zero()) {
+                body.padded(inset.map(|side| side.map(Length::from)))
+            } else {
+                body
+            }
+        };
 
-        // Select the appropriate base and expansion for the child depending
-        // on whether it is automatically or relatively sized.
-        let pod = Regions::one(size, expand);
-        let mut frame = body.layout(engine, styles, pod)?.into_frame();
+        let body = pad(self.body(styles).unwrap_or_default());
+        let overflow = self.overflow(styles);
+        let mut frame = match overflow {
+            Overflow::Visible | Overflow::Clip => {
+                // Select the appropriate base and expansion for the child
+                // depending on whether it is automatically or relatively sized.
+                let pod = Regions::one(size, expand);
+                body.layout(engine, styles, pod)?.into_frame()
+            }
+            Overflow::Scale => {
+                // Measure the content without breaking it along the fixed
+                // width.
+                let width = if expand.x { Abs::inf() } else { size.x };
+                let mut pod =
+                    Regions::one(Size::new(width, Abs::inf()), Axes::splat(false));
+                pod.full = size.y;
+                let mut frame = body.layout(engine, styles, pod)?.into_frame();
+                let factor = shrink_factor(frame.size(), size, expand);
+                if factor < 1.0 {
+                    scale_frame(&mut frame, factor);
+                }
+                frame
+            }
+            Overflow::Ellipsis => {
+                let mut pod = Regions::one(
+                    Size::new(size.x, Abs::inf()),
+                    Axes::new(expand.x, false),
+                );
+                pod.full = size.y;
+                let fits =
+                    |frame: &Frame| shrink_factor(frame.size(), size, expand) >= 1.0;
+                let frame = body.layout(engine, styles, pod)?.into_frame();
+                if fits(&frame) {
+                    frame
+                } else {
+                    let text = self.body(styles).unwrap_or_default().plain_text();
+                    truncate(engine, &text, styles, pod, fits, pad)?
+                }
+            }
+        };
 
         // Enforce correct size.
         *frame.size_mut() = expand.select(size, frame.size());
@@ -157,7 +248,7 @@ impl Layout for BoxElem {
         let stroke = self.stroke(styles).map(|s| s.map(Stroke::unwrap_or_default));
 
         // Clip the contents
-        if self.clip(styles) {
+        if self.clip(styles) || matches!(overflow, Overflow::Clip | Overflow::Ellipsis) {
             let outset = self.outset(styles).relative_to(frame.size());
             let size = frame.size() + outset.sum_by_axis();
             let radius = self.radius(styles);
@@ -179,6 +270,85 @@ impl Layout for BoxElem {
     }
 }
 
+/// What to do with content that does not fit into a box.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum Overflow {
+    /// The content extends beyond the box.
+    Visible,
+    /// The content is cut off at the box's edges.
+    Clip,
+    /// The content is laid out at its natural size and then uniformly scaled
+    /// down until it fits.
+    Scale,
+    /// The content is shortened and ends with an ellipsis. Only its plain text
+    /// is kept, so formatting within the box is lost when it is truncated.
+    Ellipsis,
+}
+
+/// The factor by which content of the given size must be scaled down to fit
+/// into the fixed axes of the target size.
+fn shrink_factor(content: Size, target: Size, fixed: Axes<bool>) -> f64 {
+    let mut factor = 1.0_f64;
+    for (content, target, fixed) in
+        [(content.x, target.x, fixed.x), (content.y, target.y, fixed.y)]
+    {
+        if fixed && !target.fits(content) {
+            factor = factor.min(target / content);
+        }
+    }
+    factor
+}
+
+/// Uniformly scale a frame, keeping its top-left corner in place.
+pub(crate) fn scale_frame(frame: &mut Frame, factor: f64) {
+    let baseline = frame.has_baseline().then(|| frame.baseline() * factor);
+    frame.transform(Transform::scale(Ratio::new(factor), Ratio::new(factor)));
+    *frame.size_mut() = frame.size() * factor;
+    if let Some(baseline) = baseline {
+        frame.set_baseline(baseline);
+    }
+}
+
+/// Lay out the longest prefix of the text that fits when followed by an
+/// ellipsis.
+fn truncate(
+    engine: &mut Engine,
+    text: &str,
+    styles: StyleChain,
+    pod: Regions,
+    fits: impl Fn(&Frame) -> bool,
+    pad: impl Fn(Content) -> Content,
+) -> SourceResult<Frame> {
+    let bounds: Vec<usize> = text.char_indices().map(|(i, _)| i).collect();
+    let attempt = |engine: &mut Engine, n: usize| {
+        let end = bounds.get(n).copied().unwrap_or(text.len());
+        let mut shortened = EcoString::from(text[..end].trim_end());
+        shortened.push('…');
+        pad(TextElem::packed(shortened))
+            .layout(engine, styles, pod)
+            .map(Fragment::into_frame)
+    };
+
+    // Find the longest fitting prefix with a binary search. If not even the
+    // ellipsis alone fits, it is clipped.
+    let mut best = attempt(engine, 0)?;
+    if fits(&best) {
+        let (mut lo, mut hi) = (1, bounds.len());
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            let frame = attempt(engine, mid)?;
+            if fits(&frame) {
+                best = frame;
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+    }
+
+    Ok(best)
+}
+
 /// A block-level container.
 ///
 /// Such a container can be used to separate content, size it, and give it a
@@ -142,6 +142,16 @@ pub(super) fn breakpoints<'a>(
                 break 'hyphenate;
             }
 
+            // Prefer custom hyphenation points for the word.
+            if let Some(points) = custom_hyphenation(p, last, word) {
+                for i in points {
+                    if hyphenate_at(p, last + i) {
+                        f(last + i, Breakpoint::Hyphen);
+                    }
+                }
+                break 'hyphenate;
+            }
+
             let end = last + word.len();
             let mut offset = last;
 
@@ -249,6 +259,13 @@ fn hyphenate_at(p: &Preparation, offset: usize) -> bool {
         .unwrap_or(false)
 }
 
+/// The custom hyphenation points of the word at the given offset.
+fn custom_hyphenation(p: &Preparation, offset: usize, word: &str) -> Option<Vec<usize>> {
+    let shaped = p.find(offset)?.text()?;
+    let lang = p.lang.unwrap_or_else(|| TextElem::lang_in(shaped.styles));
+    TextElem::hyphenations_in(shaped.styles).get(word, lang)
+}
+
 /// The text language at the given offset.
 fn lang_at(p: &Preparation, offset: usize) -> Option<hypher::Lang> {
     let lang = p.lang.or_else(|| {
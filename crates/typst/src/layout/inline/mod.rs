@@ -2,6 +2,7 @@ mod linebreak;
 mod shaping;
 
 use comemo::{Prehashed, Tracked, TrackedMut};
+use ecow::eco_format;
 use unicode_bidi::{BidiInfo, Level as BidiLevel};
 use unicode_script::{Script, UnicodeScript};
 
@@ -10,20 +11,22 @@ use self::shaping::{
     is_gb_style, is_of_cjk_script, shape, ShapedGlyph, ShapedText, BEGIN_PUNCT_PAT,
     END_PUNCT_PAT,
 };
-use crate::diag::{bail, SourceResult};
+use crate::diag::{bail, warning, SourceResult};
 use crate::engine::{Engine, Route};
 use crate::eval::Tracer;
-use crate::foundations::{Content, Resolve, Smart, StyleChain};
+use crate::foundations::repr::separated_list;
+use crate::foundations::{Content, Repr, Resolve, Smart, StyleChain};
 use crate::introspection::{Introspector, Locator, MetaElem};
 use crate::layout::{
     Abs, AlignElem, Axes, BoxElem, Dir, Em, FixedAlign, Fr, Fragment, Frame, HElem,
     Layout, Point, Regions, Size, Sizing, Spacing,
 };
 use crate::math::EquationElem;
-use crate::model::{Linebreaks, ParElem};
+use crate::model::{ChangebarElem, Linebreaks, ParElem};
 use crate::syntax::Span;
 use crate::text::{
-    Lang, LinebreakElem, SmartQuoteElem, SmartQuoter, SmartQuotes, SpaceElem, TextElem,
+    Font, FontStyle, FontVariant, Lang, LinebreakElem, SmartQuoteElem, SmartQuoter,
+    SmartQuotes, SpaceElem, TextElem,
 };
 use crate::util::Numeric;
 use crate::World;
@@ -549,13 +552,16 @@ fn prepare<'a>(
 
     let mut cursor = 0;
     let mut items = Vec::with_capacity(segments.len());
+    let mut substituted = vec![];
 
     // Shape / layout the children and collect them into items.
     for (segment, styles) in segments {
         let end = cursor + segment.len();
         match segment {
             Segment::Text(_) => {
+                let start = items.len();
                 shape_range(&mut items, engine, &bidi, cursor..end, &spans, styles);
+                warn_substituted_variants(engine, &items[start..], &mut substituted);
             }
             Segment::Spacing(spacing) => match spacing {
                 Spacing::Rel(v) => {
@@ -614,6 +620,80 @@ fn prepare<'a>(
     })
 }
 
+/// Warn about text that was set in one of the requested font families, but in
+/// a different variant than requested because the family doesn't have it.
+///
+/// Each combination of font and requested variant is only reported once per
+/// paragraph. Math fonts are skipped because equations express styles through
+/// dedicated Unicode characters rather than separate font files.
+fn warn_substituted_variants(
+    engine: &mut Engine,
+    items: &[Item],
+    substituted: &mut Vec<(Font, FontVariant)>,
+) {
+    for shaped in items.iter().filter_map(Item::text) {
+        // The text's own styles determine the requested families, as a
+        // segment may consist of multiple differently styled runs.
+        let families = TextElem::font_in(shaped.styles);
+        let requested = shaped.variant;
+        for glyph in shaped.glyphs.iter() {
+            let info = glyph.font.info();
+            let available = info.variant;
+            if !variant_differs(requested, available)
+                || substituted
+                    .iter()
+                    .any(|(font, variant)| *font == glyph.font && *variant == requested)
+            {
+                continue;
+            }
+
+            let family = info.family.to_lowercase();
+            if !families.into_iter().any(|f| f.as_str() == family)
+                || glyph.font.ttf().tables().math.is_some()
+            {
+                continue;
+            }
+
+            substituted.push((glyph.font.clone(), requested));
+            engine.tracer.warn(warning!(
+                glyph.span.0,
+                "font family \"{}\" has no variant with {}, using {} instead",
+                info.family,
+                describe_variant(requested, available),
+                describe_variant(available, requested),
+            ));
+        }
+    }
+}
+
+/// Whether a substituted font variant noticeably differs from the requested
+/// one. Italic and oblique are considered interchangeable.
+fn variant_differs(requested: FontVariant, available: FontVariant) -> bool {
+    (requested.style == FontStyle::Normal) != (available.style == FontStyle::Normal)
+        || requested.weight != available.weight
+        || requested.stretch != available.stretch
+}
+
+/// Describe the properties of `variant` that differ from `other`.
+fn describe_variant(variant: FontVariant, other: FontVariant) -> String {
+    let mut parts = vec![];
+    if (variant.style == FontStyle::Normal) != (other.style == FontStyle::Normal) {
+        let style = match variant.style {
+            FontStyle::Normal => "normal",
+            FontStyle::Italic => "italic",
+            FontStyle::Oblique => "oblique",
+        };
+        parts.push(eco_format!("style {style}"));
+    }
+    if variant.weight != other.weight {
+        parts.push(eco_format!("weight {}", variant.weight.to_number()));
+    }
+    if variant.stretch != other.stretch {
+        parts.push(eco_format!("stretch {}", variant.stretch.repr()));
+    }
+    separated_list(&parts, "and")
+}
+
 /// Add some spacing between Han characters and western characters.
 /// See Requirements for Chinese Text Layout, Section 3.2.2 Mixed Text Composition in Horizontal
 /// Written Mode
@@ -1281,6 +1361,11 @@ fn commit(
     let mut top = Abs::zero();
     let mut bottom = Abs::zero();
 
+    // Lines with changed text of a diff get a change bar.
+    let bar = reordered
+        .iter()
+        .find_map(|item| ChangebarElem::inline_in(item.text()?.styles));
+
     // Build the frames and determine the height and baseline.
     let mut frames = vec![];
     for item in reordered {
@@ -1335,6 +1420,10 @@ fn commit(
         output.push_frame(Point::new(x, y), frame);
     }
 
+    if let Some(bar) = bar {
+        bar.draw(&mut output);
+    }
+
     Ok(output)
 }
 
@@ -10,12 +10,12 @@ use unicode_script::{Script, UnicodeScript};
 
 use super::SpanMapper;
 use crate::engine::Engine;
-use crate::foundations::StyleChain;
+use crate::foundations::{Smart, StyleChain};
 use crate::layout::{Abs, Dir, Em, Frame, FrameItem, Point, Size};
 use crate::syntax::Span;
 use crate::text::{
-    decorate, families, features, variant, Font, FontVariant, Glyph, Lang, Region,
-    TextElem, TextItem,
+    decorate, families, features, variant, Font, FontVariant, Glyph, Lang, NumberWidth,
+    Region, TextElem, TextItem,
 };
 use crate::util::SliceExt;
 use crate::World;
@@ -230,16 +230,14 @@ impl<'a> ShapedText<'a> {
         let decos = TextElem::deco_in(self.styles);
         let fill = TextElem::fill_in(self.styles);
 
-        for ((font, y_offset), group) in
-            self.glyphs.as_ref().group_by_key(|g| (g.font.clone(), g.y_offset))
-        {
+        for (font, group) in self.glyphs.as_ref().group_by_key(|g| g.font.clone()) {
             let mut range = group[0].range.clone();
             for glyph in group {
                 range.start = range.start.min(glyph.range.start);
                 range.end = range.end.max(glyph.range.end);
             }
 
-            let pos = Point::new(offset, top + shift - y_offset.at(self.size));
+            let pos = Point::new(offset, top + shift);
             let glyphs = group
                 .iter()
                 .map(|glyph| {
@@ -271,6 +269,7 @@ impl<'a> ShapedText<'a> {
                             + justification_left
                             + justification_right,
                         x_offset: glyph.x_offset + justification_left,
+                        y_offset: glyph.y_offset,
                         range: (glyph.range.start - range.start).saturating_as()
                             ..(glyph.range.end - range.start).saturating_as(),
                         span: glyph.span,
@@ -658,33 +657,21 @@ fn shape_segment<'a>(
 
     ctx.used.push(font.clone());
 
-    // Fill the buffer with our text.
-    let mut buffer = UnicodeBuffer::new();
-    buffer.push_str(text);
-    buffer.set_language(language(ctx.styles));
-    if let Some(script) = TextElem::script_in(ctx.styles).as_custom().and_then(|script| {
-        rustybuzz::Script::from_iso15924_tag(Tag::from_bytes(script.as_bytes()))
-    }) {
-        buffer.set_script(script)
-    }
-    buffer.set_direction(match ctx.dir {
-        Dir::LTR => rustybuzz::Direction::LeftToRight,
-        Dir::RTL => rustybuzz::Direction::RightToLeft,
-        _ => unimplemented!("vertical text layout"),
-    });
-
-    // Shape!
-    let buffer = rustybuzz::shape(font.rusty(), &ctx.features, buffer);
-    let infos = buffer.glyph_infos();
-    let pos = buffer.glyph_positions();
+    // Shape the text or just map it to glyphs.
     let ltr = ctx.dir.is_positive();
+    let infos = if TextElem::shaping_in(ctx.styles) {
+        shape_with_font(ctx, &font, text)
+    } else {
+        map_to_glyphs(&font, text, ltr)
+    };
+    let tabular = synthesized_tabular_width(ctx.styles, &font);
 
     // Collect the shaped glyphs, doing fallback and shaping parts again with
     // the next font if necessary.
     let mut i = 0;
     while i < infos.len() {
         let info = &infos[i];
-        let cluster = info.cluster as usize;
+        let cluster = info.cluster;
 
         // Add the glyph to the shaped output.
         if info.glyph_id != 0 {
@@ -693,21 +680,29 @@ fn shape_segment<'a>(
             let end = base
                 + if ltr { i.checked_add(1) } else { i.checked_sub(1) }
                     .and_then(|last| infos.get(last))
-                    .map_or(text.len(), |info| info.cluster as usize);
+                    .map_or(text.len(), |info| info.cluster);
 
             let c = text[cluster..].chars().next().unwrap();
             let script = c.script();
-            let x_advance = font.to_em(pos[i].x_advance);
+            let mut x_advance = font.to_em(info.x_advance);
+            let mut x_offset = font.to_em(info.x_offset);
+
+            // Center digits in the synthesized tabular width.
+            if let Some(width) = tabular.filter(|_| c.is_ascii_digit()) {
+                x_offset += (width - x_advance) / 2.0;
+                x_advance = width;
+            }
+
             ctx.glyphs.push(ShapedGlyph {
                 font: font.clone(),
                 glyph_id: info.glyph_id as u16,
                 // TODO: Don't ignore y_advance.
                 x_advance,
-                x_offset: font.to_em(pos[i].x_offset),
-                y_offset: font.to_em(pos[i].y_offset),
+                x_offset,
+                y_offset: font.to_em(info.y_offset),
                 adjustability: Adjustability::default(),
                 range: start..end,
-                safe_to_break: !info.unsafe_to_break(),
+                safe_to_break: info.safe_to_break,
                 c,
                 span: ctx.spans.span_at(start),
                 is_justifiable: is_justifiable(
@@ -744,10 +739,10 @@ fn shape_segment<'a>(
             // Glyphs:   E   C   _   _   A
             // Clusters: 8   6   4   2   0
             //                  k=2 i=3
-            let start = infos[if ltr { k } else { i }].cluster as usize;
+            let start = infos[if ltr { k } else { i }].cluster;
             let end = if ltr { i.checked_add(1) } else { k.checked_sub(1) }
                 .and_then(|last| infos.get(last))
-                .map_or(text.len(), |info| info.cluster as usize);
+                .map_or(text.len(), |info| info.cluster);
 
             // Trim half-baked cluster.
             let remove = base + start..base + end;
@@ -765,6 +760,85 @@ fn shape_segment<'a>(
     ctx.used.pop();
 }
 
+/// A glyph as determined by shaping, before font fallback.
+struct RawGlyph {
+    /// The glyph's index in the font, zero if the font has no glyph.
+    glyph_id: u32,
+    /// The start of the glyph's cluster in the text.
+    cluster: usize,
+    /// The advance width of the glyph in font units.
+    x_advance: i32,
+    /// The horizontal offset of the glyph in font units.
+    x_offset: i32,
+    /// The vertical offset of the glyph in font units.
+    y_offset: i32,
+    /// Whether the text may be broken before the glyph without reshaping.
+    safe_to_break: bool,
+}
+
+/// Shape text with a font's OpenType tables.
+fn shape_with_font(ctx: &ShapingContext, font: &Font, text: &str) -> Vec<RawGlyph> {
+    // Fill the buffer with our text.
+    let mut buffer = UnicodeBuffer::new();
+    buffer.push_str(text);
+    buffer.set_language(language(ctx.styles));
+    if let Some(script) = TextElem::script_in(ctx.styles).as_custom().and_then(|script| {
+        rustybuzz::Script::from_iso15924_tag(Tag::from_bytes(script.as_bytes()))
+    }) {
+        buffer.set_script(script)
+    }
+    buffer.set_direction(match ctx.dir {
+        Dir::LTR => rustybuzz::Direction::LeftToRight,
+        Dir::RTL => rustybuzz::Direction::RightToLeft,
+        _ => unimplemented!("vertical text layout"),
+    });
+
+    // Shape!
+    let buffer = rustybuzz::shape(font.rusty(), &ctx.features, buffer);
+    buffer
+        .glyph_infos()
+        .iter()
+        .zip(buffer.glyph_positions())
+        .map(|(info, pos)| RawGlyph {
+            glyph_id: info.glyph_id,
+            cluster: info.cluster as usize,
+            x_advance: pos.x_advance,
+            x_offset: pos.x_offset,
+            y_offset: pos.y_offset,
+            safe_to_break: !info.unsafe_to_break(),
+        })
+        .collect()
+}
+
+/// Map each character of the text to the font's glyph for it, without
+/// applying the font's OpenType tables.
+fn map_to_glyphs(font: &Font, text: &str, ltr: bool) -> Vec<RawGlyph> {
+    let ttf = font.ttf();
+    let mut glyphs: Vec<_> = text
+        .char_indices()
+        .map(|(cluster, c)| {
+            let id = ttf.glyph_index(c);
+            RawGlyph {
+                glyph_id: id.map_or(0, |id| id.0.into()),
+                cluster,
+                x_advance: id
+                    .and_then(|id| ttf.glyph_hor_advance(id))
+                    .map_or(0, i32::from),
+                x_offset: 0,
+                y_offset: 0,
+                safe_to_break: true,
+            }
+        })
+        .collect();
+
+    // Glyphs are in visual order.
+    if !ltr {
+        glyphs.reverse();
+    }
+
+    glyphs
+}
+
 /// Shape the text with tofus from the given font.
 fn shape_tofus(ctx: &mut ShapingContext, base: usize, text: &str, font: Font) {
     let x_advance = font.advance(0).unwrap_or_default();
@@ -858,6 +932,25 @@ fn calculate_adjustability(ctx: &mut ShapingContext, lang: Lang, region: Option<
     }
 }
 
+/// The width of the widest digit if tabular numbers are requested, but the
+/// font doesn't support them through the OpenType `tnum` feature.
+fn synthesized_tabular_width(styles: StyleChain, font: &Font) -> Option<Em> {
+    if TextElem::number_width_in(styles) != Smart::Custom(NumberWidth::Tabular) {
+        return None;
+    }
+
+    let ttf = font.ttf();
+    if ttf
+        .tables()
+        .gsub
+        .is_some_and(|gsub| gsub.features.find(Tag::from_bytes(b"tnum")).is_some())
+    {
+        return None;
+    }
+
+    ('0'..='9').filter_map(|c| font.advance(ttf.glyph_index(c)?.0)).max()
+}
+
 /// Difference between non-breaking and normal space.
 fn nbsp_delta(font: &Font) -> Option<Em> {
     let space = font.ttf().glyph_index(' ')?.0;
@@ -112,6 +112,7 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<RepeatElem>();
     global.define_elem::<MoveElem>();
     global.define_elem::<ScaleElem>();
+    global.define_elem::<FitElem>();
     global.define_elem::<RotateElem>();
     global.define_elem::<HideElem>();
     global.define_func::<measure>();
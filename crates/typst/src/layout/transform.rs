@@ -1,10 +1,12 @@
 use crate::diag::SourceResult;
 use crate::engine::Engine;
-use crate::foundations::{elem, Content, Resolve, StyleChain};
+use crate::foundations::{elem, Content, Resolve, Smart, StyleChain};
+use crate::layout::container::scale_frame;
 use crate::layout::{
     Abs, Align, Angle, Axes, FixedAlign, Fragment, HAlign, Layout, Length, Ratio,
-    Regions, Rel, VAlign,
+    Regions, Rel, Size, VAlign,
 };
+use crate::util::Numeric;
 
 /// Moves content without affecting layout.
 ///
@@ -190,6 +192,85 @@ impl Layout for ScaleElem {
     }
 }
 
+/// Scales content uniformly to fit into the given bounds.
+///
+/// The content is laid out at its natural size, without automatic line breaks,
+/// and then scaled down until it fits. Unlike with [`scale`]($scale), the
+/// layout sees the scaled size. This is useful for headlines, certificates,
+/// and table cells with content of varying length.
+///
+/// # Example
+/// ```example
+/// #set page(width: 120pt)
+/// #fit[= A rather long headline]
+/// #fit(height: 1em)[Large text in a line]
+/// ```
+#[elem(Layout)]
+pub struct FitElem {
+    /// The maximum width of the content. If `{auto}`, the available width is
+    /// used.
+    pub width: Smart<Rel<Length>>,
+
+    /// The maximum height of the content. If `{auto}`, the height is not
+    /// restricted.
+    pub height: Smart<Rel<Length>>,
+
+    /// Whether to also scale up content that is smaller than the bounds.
+    ///
+    /// ```example
+    /// #fit(width: 100%, grow: true)[Wide]
+    /// ```
+    #[default(false)]
+    pub grow: bool,
+
+    /// The content to fit.
+    #[required]
+    pub body: Content,
+}
+
+impl Layout for FitElem {
+    #[tracing::instrument(name = "FitElem::layout", skip_all)]
+    fn layout(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let base = regions.base();
+        let width = self
+            .width(styles)
+            .map_or(base.x, |width| width.resolve(styles).relative_to(base.x));
+        let height = self
+            .height(styles)
+            .as_custom()
+            .map(|height| height.resolve(styles).relative_to(base.y));
+
+        // Measure the content at its natural size.
+        let mut pod = Regions::one(Size::splat(Abs::inf()), Axes::splat(false));
+        pod.full = base.y;
+        let mut frame = self.body().layout(engine, styles, pod)?.into_frame();
+
+        let mut factor = f64::INFINITY;
+        for (bound, extent) in [(Some(width), frame.width()), (height, frame.height())] {
+            if let Some(bound) = bound.filter(|b| b.is_finite()) {
+                if !extent.is_zero() {
+                    factor = factor.min(bound / extent);
+                }
+            }
+        }
+
+        if !self.grow(styles) {
+            factor = factor.min(1.0);
+        }
+
+        if factor.is_finite() && factor != 1.0 {
+            scale_frame(&mut frame, factor);
+        }
+
+        Ok(Fragment::frame(frame))
+    }
+}
+
 /// A scale-skew-translate transformation.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct Transform {
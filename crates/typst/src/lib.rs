@@ -64,9 +64,9 @@ use ecow::{EcoString, EcoVec};
 
 use crate::diag::{warning, FileResult, SourceDiagnostic, SourceResult};
 use crate::engine::{Engine, Route};
-use crate::eval::Tracer;
+use crate::eval::{Limits, Progress, Tracer};
 use crate::foundations::{
-    Array, Bytes, Content, Datetime, Module, Scope, StyleChain, Styles,
+    Bytes, Content, Datetime, Module, Scope, StyleChain, Styles, Target,
 };
 use crate::introspection::{Introspector, Locator};
 use crate::layout::{Align, Dir, LayoutRoot};
@@ -83,22 +83,25 @@ use crate::visualize::Color;
 /// Requires a mutable reference to a tracer. Such a tracer can be created with
 /// `Tracer::new()`. Independently of whether compilation succeeded, calling
 /// `tracer.warnings()` after compilation will return all compiler warnings.
+/// With `tracer.set_lenient(true)`, images that fail to decode are replaced by
+/// a placeholder and reported as a warning instead of failing the compilation.
+/// A progress sink registered with `tracer.set_progress(..)` is notified when
+/// compilation enters a new phase.
 #[tracing::instrument(skip_all)]
 pub fn compile(world: &dyn World, tracer: &mut Tracer) -> SourceResult<Document> {
     // Call `track` on the world just once to keep comemo's ID stable.
     let world = world.track();
 
     // Try to evaluate the source file into a module.
-    let module = crate::eval::eval(
-        world,
-        Route::default().track(),
-        tracer.track_mut(),
-        &world.main(),
-    )
-    .map_err(deduplicate)?;
+    let main = world.main();
+    let module =
+        crate::eval::eval(world, Route::default().track(), tracer.track_mut(), &main)
+            .map_err(deduplicate)?;
 
     // Typeset the module's content, relayouting until convergence.
-    typeset(world, tracer, &module.content()).map_err(deduplicate)
+    let document = typeset(world, tracer, &module.content()).map_err(deduplicate)?;
+    tracer.report(Progress::Done { pages: document.pages.len() });
+    Ok(document)
 }
 
 /// Relayout until introspection converges.
@@ -117,9 +120,11 @@ fn typeset(
     // If that doesn't happen within five attempts, we give up.
     loop {
         tracing::info!("Layout iteration {iter}");
+        tracer.report(Progress::Layout { iteration: iter, pages: document.pages.len() });
 
-        // Clear delayed errors.
+        // Clear delayed errors and the rules applied in the last iteration.
         tracer.delayed();
+        tracer.clear_rules();
 
         let constraint = <Introspector as Validate>::Constraint::new();
         let mut locator = Locator::new();
@@ -183,6 +188,26 @@ fn deduplicate(mut diags: EcoVec<SourceDiagnostic>) -> EcoVec<SourceDiagnostic>
 /// clients like language servers can also retain the source files and
 /// [edit](Source::edit) them in-place to benefit from better incremental
 /// performance.
+///
+/// # Invalidation
+/// There are no explicit revisions to bump when something changes. Instead,
+/// the compiler's memoization records which methods of the world a computation
+/// called, with which arguments, and a hash of what they returned. On the next
+/// compilation, a cached result is reused only if all of these calls still
+/// return values with the same hashes. Consequently:
+///
+/// - To invalidate a file, simply return its new contents from `source` or
+///   `file`. Everything that didn't read the file remains cached.
+/// - To invalidate fonts, return a different [`FontBook`] from `book` and
+///   the new fonts from `font`. As the book is prehashed, this is cheap to
+///   check.
+/// - Results must be consistent within a single compilation: returning
+///   different values for the same call while compiling leads to undefined
+///   (but memory safe) results.
+///
+/// Long-running applications should call `comemo::evict` after each
+/// compilation to discard cache entries that weren't used for a while, like
+/// `typst watch` does.
 #[comemo::track]
 pub trait World {
     /// The standard library.
@@ -249,6 +274,12 @@ pub struct Library {
     /// The default style properties (for page size, font selection, and
     /// everything else configurable via set and show rules).
     pub styles: Styles,
+    /// Limits for the evaluation of code, like the maximum function call
+    /// depth.
+    pub limits: Limits,
+    /// The kind of output the document is compiled for, as returned by the
+    /// `target` function.
+    pub target: Target,
 }
 
 impl Library {
@@ -256,7 +287,13 @@ impl Library {
     pub fn build() -> Self {
         let math = math::module();
         let global = global(math.clone());
-        Self { global, math, styles: Styles::new() }
+        Self {
+            global,
+            math,
+            styles: Styles::new(),
+            limits: Limits::default(),
+            target: Target::default(),
+        }
     }
 }
 
@@ -310,7 +347,6 @@ fn prelude(global: &mut Scope) {
     global.define("oklch", Color::oklch_data());
     global.define("rgb", Color::rgb_data());
     global.define("cmyk", Color::cmyk_data());
-    global.define("range", Array::range_data());
     global.define("ltr", Dir::LTR);
     global.define("rtl", Dir::RTL);
     global.define("ttb", Dir::TTB);
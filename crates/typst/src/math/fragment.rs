@@ -312,6 +312,7 @@ impl GlyphFragment {
                 id: self.id.0,
                 x_advance: Em::from_length(self.width, self.font_size),
                 x_offset: Em::zero(),
+                y_offset: Em::zero(),
                 range: 0..self.c.len_utf8() as u16,
                 span: (self.span, 0),
             }],
@@ -74,6 +74,10 @@ use crate::World;
 /// | Economics       | `{"harvard-cite-them-right"}`                          |
 /// | Physics         | `{"american-physics-society"}`                         |
 ///
+/// The style also decides how the entries are sorted. Names and titles are
+/// compared by their Unicode code points, so letters with diacritics might not
+/// be sorted as is customary in the [text language]($text.lang).
+///
 /// # Example
 /// ```example
 /// This was already noted by
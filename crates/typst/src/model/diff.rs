@@ -0,0 +1,279 @@
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{bail, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    cast, dict, elem, ty, Cast, Content, Dict, IntoValue, Repr, Resolve, Show, StyleChain,
+};
+use crate::layout::{
+    Abs, Em, Fragment, Frame, FrameItem, Layout, Length, Point, Regions, Rel,
+};
+use crate::syntax::Span;
+use crate::text::{StrikeElem, TextElem, UnderlineElem};
+use crate::visualize::{Color, FixedStroke, Geometry, Paint, Stroke};
+
+/// Shows the changes between two versions of a text.
+///
+/// The changes are given as a sequence of parts. Plain content is shown as
+/// is, while a dictionary with an `insert` or a `delete` key marks content
+/// that was added or removed. Insertions are underlined and deletions are
+/// struck through, each in their own color. Optionally, a change bar is
+/// drawn in the margin next to each line that contains changed text.
+///
+/// Since the parts are regular values, a redline can be produced from data,
+/// for instance by mapping over the output of a diffing package.
+///
+/// # Example
+/// ```example
+/// #diff(
+///   [The fee is ],
+///   (delete: [ten]),
+///   (insert: [twelve]),
+///   [ dollars per month.],
+/// )
+/// ```
+#[elem(Show)]
+pub struct DiffElem {
+    /// The color of inserted content.
+    ///
+    /// ```example
+    /// #set diff(insert: blue)
+    /// #diff([A ], (insert: [new]), [ term.])
+    /// ```
+    #[default(Color::GREEN.into())]
+    pub insert: Paint,
+
+    /// The color of deleted content.
+    #[default(Color::RED.into())]
+    pub delete: Paint,
+
+    /// Whether to draw [change bars]($changebar) next to changed lines.
+    ///
+    /// The bars are positioned and stroked according to the `changebar` set
+    /// rules in effect. Unless a stroke paint is set there, they take the
+    /// color of the change.
+    ///
+    /// ```example
+    /// #set diff(bars: true)
+    /// #set changebar(dx: -0.75em)
+    /// #diff([Keep ], (delete: [this]), [.])
+    /// ```
+    #[default(false)]
+    pub bars: bool,
+
+    /// The parts of the text.
+    #[variadic]
+    pub children: Vec<DiffPart>,
+}
+
+impl Show for DiffElem {
+    #[tracing::instrument(name = "DiffElem::show", skip_all)]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let bar = self.bars(styles);
+        Ok(Content::sequence(self.children().iter().map(|part| {
+            let (kind, body, paint) = match part {
+                DiffPart::Keep(body) => return body.clone(),
+                DiffPart::Insert(body) => (ChangeKind::Insert, body, self.insert(styles)),
+                DiffPart::Delete(body) => (ChangeKind::Delete, body, self.delete(styles)),
+            };
+
+            ChangeElem::new(kind, body.clone(), paint, bar)
+                .pack()
+                .spanned(self.span())
+        })))
+    }
+}
+
+/// A part of a [diff](DiffElem).
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum DiffPart {
+    /// Content that is the same in both versions.
+    Keep(Content),
+    /// Content that was added in the new version.
+    Insert(Content),
+    /// Content that was removed in the new version.
+    Delete(Content),
+}
+
+cast! {
+    DiffPart,
+    self => match self {
+        Self::Keep(body) => body.into_value(),
+        Self::Insert(body) => dict! { "insert" => body }.into_value(),
+        Self::Delete(body) => dict! { "delete" => body }.into_value(),
+    },
+    v: Content => Self::Keep(v),
+    mut v: Dict => {
+        let part = match (v.take("insert").ok(), v.take("delete").ok()) {
+            (Some(body), None) => Self::Insert(body.cast()?),
+            (None, Some(body)) => Self::Delete(body.cast()?),
+            _ => bail!("a change must have either an `insert` or a `delete` key"),
+        };
+        v.finish(&["insert", "delete"])?;
+        part
+    },
+}
+
+/// A single insertion or deletion in a [diff](DiffElem).
+#[elem(Show)]
+struct ChangeElem {
+    /// Whether the content was inserted or deleted.
+    #[required]
+    kind: ChangeKind,
+
+    /// The changed content.
+    #[required]
+    body: Content,
+
+    /// The color of the change.
+    #[required]
+    paint: Paint,
+
+    /// Whether to draw a change bar.
+    #[required]
+    bar: bool,
+}
+
+/// Whether a part of a diff was inserted or deleted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+enum ChangeKind {
+    Insert,
+    Delete,
+}
+
+impl Show for ChangeElem {
+    #[tracing::instrument(name = "ChangeElem::show", skip_all)]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let mut body =
+            self.body().clone().styled(TextElem::set_fill(self.paint().clone()));
+
+        // The bar is drawn when the lines of the paragraph are built.
+        if *self.bar() {
+            let stroke = ChangebarElem::stroke_in(styles).unwrap_or(FixedStroke {
+                paint: self.paint().clone(),
+                thickness: Em::new(0.1).resolve(styles),
+                ..FixedStroke::default()
+            });
+            let bar = ChangeBar {
+                dx: ChangebarElem::dx_in(styles),
+                stroke,
+                span: self.span(),
+            };
+            body = body.styled(ChangebarElem::set_inline(Some(bar)));
+        }
+
+        Ok(match self.kind() {
+            ChangeKind::Insert => UnderlineElem::new(body).pack(),
+            ChangeKind::Delete => StrikeElem::new(body).pack(),
+        })
+    }
+}
+
+/// A vertical bar next to changed content.
+#[ty]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ChangeBar {
+    /// The horizontal position of the bar, relative to the start and width of
+    /// the content.
+    dx: Rel<Abs>,
+    /// How to stroke the bar.
+    stroke: FixedStroke,
+    /// The span of the marked content.
+    span: Span,
+}
+
+impl ChangeBar {
+    /// Draw the bar along the full height of a frame.
+    pub(crate) fn draw(&self, frame: &mut Frame) {
+        if frame.height() <= Abs::zero() {
+            return;
+        }
+
+        let pos = Point::with_x(self.dx.relative_to(frame.width()));
+        let shape =
+            Geometry::Line(Point::with_y(frame.height())).stroked(self.stroke.clone());
+        frame.push(pos, FrameItem::Shape(shape, self.span));
+    }
+}
+
+impl Repr for ChangeBar {
+    fn repr(&self) -> EcoString {
+        eco_format!("{self:?}")
+    }
+}
+
+cast! {
+    type ChangeBar,
+}
+
+/// Marks content with a bar in the margin.
+///
+/// The bar spans the full height of the content. If the content breaks across
+/// pages or columns, each part gets its own bar. This is useful to highlight
+/// changed passages in a revised document or to set off a section with a
+/// vertical rule.
+///
+/// The bars of a [diff]($diff) are configured with set rules for this
+/// function, too. They are drawn next to each line with changed text.
+///
+/// # Example
+/// ```example
+/// #set page(margin: (left: 1.5cm))
+/// This paragraph is unchanged.
+///
+/// #changebar[
+///   This paragraph was revised and
+///   is marked with a change bar.
+/// ]
+/// ```
+#[elem(Layout)]
+pub struct ChangebarElem {
+    /// The horizontal position of the bar, relative to the start of the
+    /// content. Negative values move the bar into the left margin, while
+    /// values beyond `{100%}` move it into the right margin.
+    ///
+    /// ```example
+    /// #changebar(dx: 100% + 0.5em)[
+    ///   Marked on the right.
+    /// ]
+    /// ```
+    #[resolve]
+    #[default(Em::new(-1.0).into())]
+    pub dx: Rel<Length>,
+
+    /// How to [stroke]($stroke) the bar.
+    #[resolve]
+    #[fold]
+    pub stroke: Stroke,
+
+    /// The content to mark.
+    #[required]
+    pub body: Content,
+
+    /// The change bar for lines with changed text.
+    #[internal]
+    #[ghost]
+    pub inline: Option<ChangeBar>,
+}
+
+impl Layout for ChangebarElem {
+    #[tracing::instrument(name = "ChangebarElem::layout", skip_all)]
+    fn layout(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let mut fragment = self.body().layout(engine, styles, regions)?;
+        let bar = ChangeBar {
+            dx: self.dx(styles),
+            stroke: self.stroke(styles).unwrap_or_default(),
+            span: self.span(),
+        };
+        for frame in &mut fragment {
+            bar.draw(frame);
+        }
+
+        Ok(fragment)
+    }
+}
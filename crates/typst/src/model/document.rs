@@ -1,12 +1,17 @@
+use std::collections::HashSet;
+
 use ecow::EcoString;
 
 use crate::diag::{bail, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, Args, Array, Construct, Content, Datetime, Smart, StyleChain, Value,
+    cast, elem, Args, Array, Construct, Content, Datetime, Label, Selector, Smart,
+    StyleChain, Value,
 };
 use crate::introspection::{Introspector, ManualPageCounter};
-use crate::layout::{Frame, LayoutRoot, PageElem};
+use crate::layout::{Frame, FrameItem, LayoutRoot, PageElem, Point, Position};
+use crate::model::{FigureElem, HeadingElem};
+use crate::text::TextStats;
 
 /// The root element of a document and its metadata.
 ///
@@ -154,6 +159,133 @@ pub struct Document {
     pub introspector: Introspector,
 }
 
+impl Document {
+    /// Query the finished document for all elements matching the selector,
+    /// in document order, along with their resolved positions.
+    ///
+    /// This lets embedders extract headings, links or metadata from a compiled
+    /// document without exporting it. The fields and label of each element are
+    /// available through [`Content::fields`] and [`Content::label`].
+    pub fn query(&self, selector: &Selector) -> Vec<(Content, Position)> {
+        self.introspector
+            .query(selector)
+            .into_iter()
+            .filter_map(|elem| {
+                let position = self.introspector.position(elem.location()?);
+                Some((elem.into_inner(), position))
+            })
+            .collect()
+    }
+
+    /// Count the words, characters and pages of the finished document.
+    ///
+    /// Only text that ends up in the document is counted, including headers,
+    /// footers and generated content like the outline.
+    pub fn stats(&self) -> DocumentStats {
+        let mut text = TextStats::default();
+        for page in &self.pages {
+            let mut page_text = PageText::default();
+            page_text.collect(page, Point::zero());
+            text += TextStats::of(&page_text.text);
+        }
+
+        DocumentStats {
+            words: text.words,
+            characters: text.characters,
+            pages: self.pages.len(),
+        }
+    }
+
+    /// The labelled headings and figures of the document along with their
+    /// positions, sorted by label.
+    ///
+    /// Exporters that support named destinations create one per entry, so
+    /// that external links like `document.pdf#intro` jump to the element
+    /// labelled `<intro>`. Labels that are attached to more than one heading
+    /// or figure are ambiguous and thus left out.
+    pub fn named_destinations(&self) -> Vec<(Label, Position)> {
+        let mut seen = HashSet::new();
+        let mut ambiguous = HashSet::new();
+        let mut dests = vec![];
+        for elem in self.introspector.all() {
+            if !elem.is::<HeadingElem>() && !elem.is::<FigureElem>() {
+                continue;
+            }
+
+            let (Some(label), Some(location)) = (elem.label(), elem.location()) else {
+                continue;
+            };
+
+            if !seen.insert(label) {
+                ambiguous.insert(label);
+                continue;
+            }
+
+            dests.push((label, self.introspector.position(location)));
+        }
+
+        dests.retain(|(label, _)| !ambiguous.contains(label));
+        dests.sort_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+        dests
+    }
+}
+
+/// The text of a page, collected for counting.
+#[derive(Default)]
+struct PageText {
+    text: String,
+    /// Where the previous text run ended.
+    end: Option<Point>,
+    /// Whether the previous text run ended in a hyphen at a line break.
+    hyphenated: bool,
+}
+
+impl PageText {
+    /// Collect the text of a frame.
+    ///
+    /// Text runs that continue exactly where the previous one ended are
+    /// joined without a separator, so that a word whose parts are styled
+    /// differently (like `*Ty*pst`) is counted once. The same goes for a word
+    /// that is hyphenated across lines. All other runs are separated by a
+    /// space.
+    fn collect(&mut self, frame: &Frame, offset: Point) {
+        for (pos, item) in frame.items() {
+            let pos = offset + *pos;
+            match item {
+                FrameItem::Group(group) => self.collect(&group.frame, pos),
+                FrameItem::Text(text) => {
+                    let continues = self.end.is_some_and(|end| {
+                        end.x.approx_eq(pos.x) && end.y.approx_eq(pos.y)
+                    });
+                    let next_line = self.end.is_some_and(|end| end.y < pos.y);
+                    if !continues && !(self.hyphenated && next_line) {
+                        self.text.push(' ');
+                    }
+
+                    self.text.push_str(&text.text);
+                    self.end = Some(pos + Point::with_x(text.width()));
+
+                    // Hyphens inserted by hyphenation have no text.
+                    self.hyphenated = text.text.ends_with(['-', '\u{2010}'])
+                        || text.glyphs.last().is_some_and(|g| g.range.is_empty());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Word, character and page counts of a finished document.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct DocumentStats {
+    /// The number of words.
+    pub words: usize,
+    /// The number of grapheme clusters that are not whitespace.
+    pub characters: usize,
+    /// The number of pages.
+    pub pages: usize,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,178 @@
+use ecow::EcoString;
+
+use crate::diag::{warning, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, scope, Cast, Content, NativeElement, Repr, Show, Smart, StyleChain, Synthesize,
+};
+use crate::introspection::Locatable;
+use crate::layout::{Abs, BoxElem, Em, Length, Rel, Sides, Sizing};
+use crate::visualize::Stroke;
+
+/// An interactive form field.
+///
+/// Form fields are laid out as empty, outlined boxes. When exporting to PDF,
+/// they become fillable fields that can be edited in a PDF viewer. In other
+/// export formats, only the box is visible.
+///
+/// Fields are created with the [`field.text`]($field.text),
+/// [`field.checkbox`]($field.checkbox), and
+/// [`field.signature`]($field.signature) functions. Each field in a document
+/// should have a unique name. PDF viewers treat fields with the same name as
+/// one field, so Typst warns about names that are used more than once.
+///
+/// # Example
+/// ```example
+/// Name: #field.text("name") \
+/// Subscribe: #field.checkbox("subscribe", checked: true) \
+/// Signature: #field.signature("signature")
+/// ```
+#[elem(scope, Locatable, Synthesize, Show)]
+pub struct FieldElem {
+    /// The kind of field.
+    #[required]
+    pub kind: FieldKind,
+
+    /// The name of the field. It identifies the field when the form is
+    /// filled in or submitted.
+    #[required]
+    pub name: EcoString,
+
+    /// The initial text of a text field.
+    #[default]
+    pub value: EcoString,
+
+    /// Whether a checkbox is initially checked.
+    #[default(false)]
+    pub checked: bool,
+
+    /// The width of the field. When set to `{auto}`, a default width for the
+    /// kind of field is used.
+    pub width: Smart<Rel<Length>>,
+
+    /// The height of the field. When set to `{auto}`, a default height for
+    /// the kind of field is used.
+    pub height: Smart<Rel<Length>>,
+
+    /// The initial text of the field, as resolved in its style chain.
+    #[internal]
+    #[synthesized]
+    pub initial_value: EcoString,
+
+    /// Whether the field is initially checked, as resolved in its style chain.
+    #[internal]
+    #[synthesized]
+    pub initial_checked: bool,
+}
+
+#[scope]
+impl FieldElem {
+    /// A single-line text field.
+    ///
+    /// ```example
+    /// City: #field.text("city", value: "Berlin")
+    /// ```
+    #[func]
+    pub fn text(
+        /// The name of the field.
+        name: EcoString,
+        /// The initial text of the field.
+        #[named]
+        #[default]
+        value: EcoString,
+    ) -> Content {
+        Self::new(FieldKind::Text, name).with_value(value).pack()
+    }
+
+    /// A checkbox that can be checked and unchecked.
+    ///
+    /// ```example
+    /// #field.checkbox("terms") I accept the terms.
+    /// ```
+    #[func]
+    pub fn checkbox(
+        /// The name of the field.
+        name: EcoString,
+        /// Whether the checkbox is initially checked.
+        #[named]
+        #[default(false)]
+        checked: bool,
+    ) -> Content {
+        Self::new(FieldKind::Checkbox, name).with_checked(checked).pack()
+    }
+
+    /// A field for a digital signature.
+    #[func]
+    pub fn signature(
+        /// The name of the field.
+        name: EcoString,
+    ) -> Content {
+        Self::new(FieldKind::Signature, name).pack()
+    }
+}
+
+impl Synthesize for FieldElem {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        // Resolve the initial state, as the exporter has no style chain.
+        self.push_initial_value(self.value(styles));
+        self.push_initial_checked(self.checked(styles));
+        Ok(())
+    }
+}
+
+impl Show for FieldElem {
+    #[tracing::instrument(name = "FieldElem::show", skip_all)]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        if self.is_duplicate(engine) {
+            engine.tracer.warn(warning!(
+                self.span(),
+                "form field name {} is used more than once",
+                self.name().repr();
+                hint: "PDF viewers treat fields with the same name as one field"
+            ));
+        }
+
+        let (width, height) = match self.kind() {
+            FieldKind::Text => (Em::new(10.0), Em::new(1.2)),
+            FieldKind::Checkbox => (Em::new(0.8), Em::new(0.8)),
+            FieldKind::Signature => (Em::new(12.0), Em::new(3.0)),
+        };
+
+        let stroke = Stroke {
+            thickness: Smart::Custom(Abs::pt(0.5).into()),
+            ..Default::default()
+        };
+
+        Ok(BoxElem::new()
+            .with_width(Sizing::Rel(self.width(styles).unwrap_or(width.into())))
+            .with_height(Smart::Custom(self.height(styles).unwrap_or(height.into())))
+            .with_stroke(Sides::splat(Some(Some(stroke))))
+            .pack()
+            .spanned(self.span()))
+    }
+}
+
+impl FieldElem {
+    /// Whether an earlier field in the document has the same name.
+    fn is_duplicate(&self, engine: &Engine) -> bool {
+        let Some(location) = self.location() else { return false };
+        engine
+            .introspector
+            .query(&Self::elem().select())
+            .iter()
+            .take_while(|elem| elem.location() != Some(location))
+            .filter_map(|elem| elem.to::<Self>())
+            .any(|field| field.name() == self.name())
+    }
+}
+
+/// The kind of a form field.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum FieldKind {
+    /// A single-line text field.
+    Text,
+    /// A checkbox.
+    Checkbox,
+    /// A field for a digital signature.
+    Signature,
+}
@@ -0,0 +1,344 @@
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{bail, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, func, repr, Content, NativeElement, Show, Smart, StyleChain,
+};
+use crate::text::{Lang, TextElem};
+
+/// Formats an amount of money.
+///
+/// The amount is rounded to the number of minor units of the currency and
+/// formatted with the decimal and group separators and the symbol placement
+/// that is conventional for the language. Supported languages are English,
+/// Dutch, French, German, Italian, Portuguese, and Spanish. By default, the
+/// language of the surrounding text is used.
+///
+/// # Example
+/// ```example
+/// #format-currency(1234.5, "USD") \
+/// #format-currency(1234.5, "EUR", lang: "de") \
+/// #format-currency(1234.5, "EUR", lang: "fr") \
+/// #format-currency(98000, "JPY")
+///
+/// #set text(lang: "de")
+/// #format-currency(1234.5, "EUR")
+/// ```
+#[func(title = "Format Currency")]
+pub fn format_currency(
+    /// The amount of money.
+    amount: f64,
+    /// The ISO 4217 code of the currency, like `{"EUR"}`.
+    code: EcoString,
+    /// The language whose conventions to use. When set to `{auto}`, the
+    /// language of the surrounding text is used.
+    #[named]
+    #[default]
+    lang: Smart<Lang>,
+) -> StrResult<Content> {
+    if code.len() != 3 || !code.chars().all(|c| c.is_ascii_uppercase()) {
+        bail!("currency code must consist of three uppercase letters");
+    }
+
+    if !amount.is_finite() {
+        bail!("amount must be a finite number");
+    }
+
+    let decimals = match code.as_str() {
+        "JPY" | "KRW" | "ISK" | "CLP" | "VND" => 0,
+        _ => 2,
+    };
+
+    let symbol = match code.as_str() {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" | "CNY" => "¥",
+        "INR" => "₹",
+        "KRW" => "₩",
+        _ => code.as_str(),
+    };
+
+    let (number, negative) = round(amount, decimals, false);
+    Ok(CurrencyElem::new(number, negative, symbol.into(), symbol == code.as_str(), lang)
+        .pack())
+}
+
+/// Formats a physical quantity with its unit.
+///
+/// The number and the unit are separated by a thin space, as recommended for
+/// SI units. Optionally, the value is scaled with an SI prefix so that it lies
+/// between 1 and 1000. The decimal separator is taken from the language of the
+/// surrounding text unless a language is given.
+///
+/// # Example
+/// ```example
+/// #format-quantity(9.81, "m/s²") \
+/// #format-quantity(1500, "m", prefix: true) \
+/// #format-quantity(0.000047, "F", prefix: true) \
+/// #format-quantity(2.5, "kg", lang: "de")
+/// ```
+#[func(title = "Format Quantity")]
+pub fn format_quantity(
+    /// The numeric value.
+    value: f64,
+    /// The symbol of the unit, like `{"m"}` or `{"Hz"}`.
+    unit: EcoString,
+    /// Whether to scale the value with an SI prefix.
+    #[named]
+    #[default(false)]
+    prefix: bool,
+    /// The maximum number of digits after the decimal separator.
+    #[named]
+    #[default(2)]
+    digits: u8,
+    /// The language whose decimal separator to use. When set to `{auto}`, the
+    /// language of the surrounding text is used.
+    #[named]
+    #[default]
+    lang: Smart<Lang>,
+) -> StrResult<Content> {
+    if !value.is_finite() {
+        bail!("value must be a finite number");
+    }
+
+    let digits = usize::from(digits);
+    let mut value = value;
+    let mut symbol = "";
+    if prefix && value != 0.0 {
+        let mut exponent = ((value.abs().log10() / 3.0).floor() as i32).clamp(-8, 8);
+
+        // Rounding may carry the value over to the next prefix, like 999.999
+        // to 1000 with two digits.
+        let scaled = value.abs() / 1000_f64.powi(exponent);
+        if exponent < 8
+            && round(scaled, digits, false).0.parse::<f64>().unwrap() >= 1000.0
+        {
+            exponent += 1;
+        }
+
+        value /= 1000_f64.powi(exponent);
+        symbol = SI_PREFIXES[(exponent + 8) as usize];
+    }
+
+    let (number, negative) = round(value, digits, true);
+    Ok(QuantityElem::new(number, negative, eco_format!("{symbol}{unit}"), lang).pack())
+}
+
+/// The SI prefixes from 10^-24 to 10^24 in steps of 1000.
+const SI_PREFIXES: [&str; 17] =
+    ["y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y"];
+
+/// An amount of money that is formatted in the language of its context.
+#[elem(Show)]
+struct CurrencyElem {
+    /// The rounded absolute amount, like `1234.50`.
+    #[required]
+    number: EcoString,
+
+    /// Whether the rounded amount is negative.
+    #[required]
+    negative: bool,
+
+    /// The currency symbol or code.
+    #[required]
+    symbol: EcoString,
+
+    /// Whether the currency has no symbol and is written as its code.
+    #[required]
+    is_code: bool,
+
+    /// The language whose conventions to use.
+    #[required]
+    lang: Smart<Lang>,
+}
+
+impl CurrencyElem {
+    /// Format the amount with the conventions of a language.
+    fn format(&self, lang: Lang) -> EcoString {
+        let conventions = Conventions::of(lang);
+        let number = conventions.separate(self.number());
+
+        // Currency codes are always separated from the number.
+        let space =
+            if conventions.symbol_space || *self.is_code() { "\u{a0}" } else { "" };
+        let sign = if *self.negative() { repr::MINUS_SIGN } else { "" };
+        let symbol = self.symbol();
+        if conventions.symbol_after {
+            eco_format!("{sign}{number}{space}{symbol}")
+        } else {
+            eco_format!("{sign}{symbol}{space}{number}")
+        }
+    }
+}
+
+impl Show for CurrencyElem {
+    #[tracing::instrument(name = "CurrencyElem::show", skip_all)]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let lang = self.lang().unwrap_or_else(|| TextElem::lang_in(styles));
+        Ok(TextElem::packed(self.format(lang)).spanned(self.span()))
+    }
+}
+
+/// A quantity that is formatted in the language of its context.
+#[elem(Show)]
+struct QuantityElem {
+    /// The rounded absolute value, like `1.5`.
+    #[required]
+    number: EcoString,
+
+    /// Whether the rounded value is negative.
+    #[required]
+    negative: bool,
+
+    /// The unit including its SI prefix.
+    #[required]
+    unit: EcoString,
+
+    /// The language whose conventions to use.
+    #[required]
+    lang: Smart<Lang>,
+}
+
+impl QuantityElem {
+    /// Format the quantity with the conventions of a language.
+    fn format(&self, lang: Lang) -> EcoString {
+        let number = Conventions::of(lang).separate(self.number());
+        let sign = if *self.negative() { repr::MINUS_SIGN } else { "" };
+        eco_format!("{sign}{number}\u{2009}{}", self.unit())
+    }
+}
+
+impl Show for QuantityElem {
+    #[tracing::instrument(name = "QuantityElem::show", skip_all)]
+    fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        let lang = self.lang().unwrap_or_else(|| TextElem::lang_in(styles));
+        Ok(TextElem::packed(self.format(lang)).spanned(self.span()))
+    }
+}
+
+/// Round the absolute value of a finite number to the given number of
+/// decimals, optionally trimming trailing zeros.
+///
+/// Also returns whether the number is negative. A number that rounds to zero
+/// is never negative, so that no minus sign is shown for it.
+fn round(value: f64, decimals: usize, trim: bool) -> (EcoString, bool) {
+    let mut number = eco_format!("{:.decimals$}", value.abs());
+    if trim && number.contains('.') {
+        let len = number.trim_end_matches('0').trim_end_matches('.').len();
+        number.truncate(len);
+    }
+
+    let negative = value < 0.0 && number.chars().any(|c| matches!(c, '1'..='9'));
+    (number, negative)
+}
+
+/// How numbers are conventionally written in a language.
+struct Conventions {
+    /// The decimal separator.
+    decimal: char,
+    /// The separator between groups of three digits.
+    group: char,
+    /// Whether a currency symbol is written after the amount.
+    symbol_after: bool,
+    /// Whether a currency symbol is separated from the amount by a space.
+    symbol_space: bool,
+}
+
+impl Conventions {
+    /// The conventions for a language.
+    fn of(lang: Lang) -> Self {
+        let (decimal, group, symbol_after, symbol_space) = match lang {
+            Lang::GERMAN | Lang::ITALIAN | Lang::PORTUGUESE | Lang::SPANISH => {
+                (',', '.', true, true)
+            }
+            Lang::FRENCH => (',', '\u{202f}', true, true),
+            Lang::DUTCH => (',', '.', false, true),
+            _ => ('.', ',', false, false),
+        };
+        Self { decimal, group, symbol_after, symbol_space }
+    }
+
+    /// Insert the separators into a rounded, non-negative number like
+    /// `1234.5`.
+    fn separate(&self, number: &str) -> EcoString {
+        let (int, frac) = number.split_once('.').unwrap_or((number, ""));
+
+        let mut buf = EcoString::new();
+        for (i, c) in int.chars().enumerate() {
+            if i > 0 && (int.len() - i) % 3 == 0 {
+                buf.push(self.group);
+            }
+            buf.push(c);
+        }
+
+        if !frac.is_empty() {
+            buf.push(self.decimal);
+            buf.push_str(frac);
+        }
+
+        buf
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn currency(amount: f64, code: &str, lang: Lang) -> EcoString {
+        let content = format_currency(amount, code.into(), Smart::Custom(lang)).unwrap();
+        content.to::<CurrencyElem>().unwrap().format(lang)
+    }
+
+    fn quantity(value: f64, unit: &str, prefix: bool, digits: u8) -> EcoString {
+        let content =
+            format_quantity(value, unit.into(), prefix, digits, Smart::Auto).unwrap();
+        content.to::<QuantityElem>().unwrap().format(Lang::ENGLISH)
+    }
+
+    #[test]
+    fn test_format_currency() {
+        assert_eq!(currency(1234.5, "USD", Lang::ENGLISH), "$1,234.50");
+        assert_eq!(currency(-3.0, "GBP", Lang::ENGLISH), "−£3.00");
+        assert_eq!(currency(1234567.891, "EUR", Lang::GERMAN), "1.234.567,89\u{a0}€");
+        assert_eq!(currency(1234.5, "EUR", Lang::FRENCH), "1\u{202f}234,50\u{a0}€");
+        assert_eq!(currency(1234.5, "EUR", Lang::DUTCH), "€\u{a0}1.234,50");
+        assert_eq!(currency(98000.4, "JPY", Lang::ENGLISH), "¥98,000");
+        assert_eq!(currency(12.0, "CHF", Lang::ENGLISH), "CHF\u{a0}12.00");
+    }
+
+    #[test]
+    fn test_format_currency_rounding_to_zero_has_no_sign() {
+        assert_eq!(currency(-0.001, "USD", Lang::ENGLISH), "$0.00");
+        assert_eq!(currency(-0.0, "USD", Lang::ENGLISH), "$0.00");
+        assert_eq!(currency(-0.4, "JPY", Lang::ENGLISH), "¥0");
+        assert_eq!(currency(-0.005001, "USD", Lang::ENGLISH), "−$0.01");
+    }
+
+    #[test]
+    fn test_format_currency_rejects_non_finite_amounts() {
+        let lang = Smart::Auto;
+        assert!(format_currency(f64::NAN, "USD".into(), lang).is_err());
+        assert!(format_currency(f64::INFINITY, "USD".into(), lang).is_err());
+        assert!(format_currency(f64::NEG_INFINITY, "USD".into(), lang).is_err());
+    }
+
+    #[test]
+    fn test_format_quantity() {
+        assert_eq!(quantity(9.81, "m/s²", false, 2), "9.81\u{2009}m/s²");
+        assert_eq!(quantity(1500.0, "m", true, 2), "1.5\u{2009}km");
+        assert_eq!(quantity(0.000047, "F", true, 2), "47\u{2009}µF");
+        assert_eq!(quantity(0.0, "A", true, 2), "0\u{2009}A");
+        assert_eq!(quantity(-0.001, "V", false, 2), "0\u{2009}V");
+        assert_eq!(quantity(2.0, "s", false, 0), "2\u{2009}s");
+    }
+
+    #[test]
+    fn test_format_quantity_carries_over_to_next_prefix() {
+        assert_eq!(quantity(999.999, "m", true, 2), "1\u{2009}km");
+        assert_eq!(quantity(999_999.0, "Hz", true, 2), "1\u{2009}MHz");
+        assert_eq!(quantity(999.994, "m", true, 2), "999.99\u{2009}m");
+        assert_eq!(quantity(-999.999, "m", true, 2), "−1\u{2009}km");
+    }
+}
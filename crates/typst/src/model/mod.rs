@@ -2,12 +2,15 @@
 
 mod bibliography;
 mod cite;
+mod diff;
 mod document;
 mod emph;
 #[path = "enum.rs"]
 mod enum_;
+mod field;
 mod figure;
 mod footnote;
+mod format;
 mod heading;
 mod link;
 mod list;
@@ -23,11 +26,14 @@ mod terms;
 
 pub use self::bibliography::*;
 pub use self::cite::*;
+pub use self::diff::*;
 pub use self::document::*;
 pub use self::emph::*;
 pub use self::enum_::*;
+pub use self::field::*;
 pub use self::figure::*;
 pub use self::footnote::*;
+pub use self::format::*;
 pub use self::heading::*;
 pub use self::link::*;
 pub use self::list::*;
@@ -61,6 +67,9 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<FigureElem>();
     global.define_elem::<FootnoteElem>();
     global.define_elem::<QuoteElem>();
+    global.define_elem::<DiffElem>();
+    global.define_elem::<ChangebarElem>();
+    global.define_elem::<FieldElem>();
     global.define_elem::<CiteElem>();
     global.define_elem::<BibliographyElem>();
     global.define_elem::<EnumElem>();
@@ -72,4 +81,6 @@ pub fn define(global: &mut Scope) {
     global.define_elem::<EmphElem>();
     global.define_elem::<StrongElem>();
     global.define_func::<numbering>();
+    global.define_func::<format_currency>();
+    global.define_func::<format_quantity>();
 }
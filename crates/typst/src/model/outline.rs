@@ -1,15 +1,19 @@
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 
+use ecow::EcoString;
+
 use crate::diag::{bail, At, SourceResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, select_where, Content, Finalize, Func, LocatableSelector,
-    NativeElement, Show, Smart, StyleChain,
+    cast, elem, scope, select_where, Behave, Behaviour, Content, Finalize, Func,
+    LocatableSelector, NativeElement, Show, Smart, StyleChain, Synthesize,
 };
 use crate::introspection::{Counter, CounterKey, Locatable};
 use crate::layout::{BoxElem, Fr, HElem, HideElem, Length, Rel, RepeatElem, Spacing};
-use crate::model::{Destination, HeadingElem, NumberingPattern, ParbreakElem, Refable};
+use crate::model::{
+    Destination, HeadingElem, Numbering, NumberingPattern, ParbreakElem, Refable,
+};
 use crate::syntax::Span;
 use crate::text::{Lang, LinebreakElem, LocalName, Region, SpaceElem, TextElem};
 use crate::util::{option_eq, NonZeroExt};
@@ -40,6 +44,13 @@ use crate::util::{option_eq, NonZeroExt};
 /// would also include figures containing tables or other material. For more
 /// details on the `where` selector, [see here]($function.where).
 ///
+/// The same works for lists of tables (`{figure.where(kind: table)}`) and code
+/// listings (`{figure.where(kind: raw)}`). The entries of such lists use the
+/// same [`fill`]($outline.fill) and page numbers as a table of contents and
+/// can be styled through [`outline.entry`]($outline.entry) just the same.
+/// Any other content can be added to an outline with an
+/// [`outline.item`]($outline.item).
+///
 /// ```example
 /// #outline(
 ///   title: [List of Figures],
@@ -183,6 +194,9 @@ pub struct OutlineElem {
 impl OutlineElem {
     #[elem]
     type OutlineEntry;
+
+    #[elem]
+    type OutlineItem;
 }
 
 impl Show for OutlineElem {
@@ -388,6 +402,96 @@ cast! {
     v: Content => Self(v),
 }
 
+/// An invisible marker that adds an entry to an outline.
+///
+/// Headings and figures show up in outlines automatically. With this element,
+/// any other content can contribute an entry, for instance a custom exercise
+/// element. The entry links to the position of the item and shows its page
+/// number. To create a separate list of items, give them a `kind` and select
+/// them with the outline's [`target`]($outline.target).
+///
+/// ```example
+/// #outline(
+///   title: [Exercises],
+///   target: outline.item.where(kind: "exercise"),
+/// )
+///
+/// #let exercise(name, body) = {
+///   outline.item(kind: "exercise")[Exercise #name]
+///   [*Exercise #name.* #body]
+/// }
+///
+/// #exercise[3.1][Prove that $1 + 1 = 2$.]
+/// ```
+#[elem(
+    name = "item",
+    title = "Outline Item",
+    Behave,
+    Locatable,
+    Synthesize,
+    Show,
+    Refable,
+    Outlinable
+)]
+pub struct OutlineItem {
+    /// The content to show in the outline.
+    #[required]
+    pub body: Content,
+
+    /// The nesting level of the entry in the outline.
+    #[default(NonZeroUsize::ONE)]
+    pub level: NonZeroUsize,
+
+    /// An arbitrary kind to distinguish items for different outlines.
+    pub kind: Option<EcoString>,
+}
+
+impl Synthesize for OutlineItem {
+    fn synthesize(&mut self, _: &mut Engine, styles: StyleChain) -> SourceResult<()> {
+        self.push_level(self.level(styles));
+        self.push_kind(self.kind(styles));
+        Ok(())
+    }
+}
+
+impl Show for OutlineItem {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(Content::empty())
+    }
+}
+
+impl Behave for OutlineItem {
+    fn behaviour(&self) -> Behaviour {
+        Behaviour::Invisible
+    }
+}
+
+impl Refable for OutlineItem {
+    fn supplement(&self) -> Content {
+        Content::empty()
+    }
+
+    fn counter(&self) -> Counter {
+        Counter::of(Self::elem())
+    }
+
+    fn numbering(&self) -> Option<Numbering> {
+        None
+    }
+}
+
+impl Outlinable for OutlineItem {
+    fn outline(&self, _: &mut Engine) -> SourceResult<Option<Content>> {
+        Ok(Some(self.body().clone()))
+    }
+
+    fn level(&self) -> NonZeroUsize {
+        // The level is materialized during synthesis, so set rules are
+        // already taken into account here.
+        self.level(StyleChain::default())
+    }
+}
+
 /// Represents each entry line in an outline, including the reference to the
 /// outlined element, its page number, and the filler content between both.
 ///
@@ -12,9 +12,10 @@ use typed_arena::Arena;
 
 use crate::diag::{bail, SourceResult};
 use crate::engine::{Engine, Route};
+use crate::eval::RuleApplication;
 use crate::foundations::{
     Content, Finalize, Guard, NativeElement, Recipe, Selector, Show, StyleChain,
-    StyleVecBuilder, Styles, Synthesize,
+    StyleVecBuilder, Styles, Synthesize, Transformation,
 };
 use crate::introspection::{Locatable, Meta, MetaElem};
 use crate::layout::{
@@ -101,8 +102,12 @@ pub fn applicable(target: &Content, styles: StyleChain) -> bool {
 
     // Find out whether any recipe matches and is unguarded.
     for recipe in styles.recipes() {
-        if recipe.applicable(target) && !target.is_guarded(Guard::Nth(n)) {
-            return true;
+        if recipe.applicable(target) {
+            if matches!(recipe.transform, Transformation::Reset) {
+                break;
+            } else if !target.is_guarded(Guard::Nth(n)) {
+                return true;
+            }
         }
         n -= 1;
     }
@@ -150,7 +155,20 @@ pub fn realize(
     for recipe in styles.recipes() {
         let guard = Guard::Nth(n);
         if recipe.applicable(target) && !target.is_guarded(guard) {
+            // A reset revokes all recipes defined before it.
+            if matches!(recipe.transform, Transformation::Reset) {
+                break;
+            }
+
             if let Some(content) = try_apply(engine, target, recipe, guard)? {
+                if engine.tracer.traces_rules() {
+                    engine.tracer.rule(RuleApplication {
+                        selector: recipe.selector.clone(),
+                        rule: recipe.span,
+                        target: target.span(),
+                    });
+                }
+
                 realized = Some(content);
                 break;
             }
@@ -241,14 +259,28 @@ fn try_apply(
             Ok(Some(Content::sequence(result)))
         }
 
+        Some(selector @ (Selector::Before { .. } | Selector::After { .. })) => {
+            // Only located elements have a position that can be compared
+            // against the start or end of the range.
+            let Some(location) = target.location() else {
+                return Ok(None);
+            };
+
+            if !engine
+                .introspector
+                .query(selector)
+                .iter()
+                .any(|elem| elem.location() == Some(location))
+            {
+                return Ok(None);
+            }
+
+            recipe.apply(engine, target.clone().guarded(guard)).map(Some)
+        }
+
         // Not supported here.
         Some(
-            Selector::Or(_)
-            | Selector::And(_)
-            | Selector::Location(_)
-            | Selector::Can(_)
-            | Selector::Before { .. }
-            | Selector::After { .. },
+            Selector::Or(_) | Selector::And(_) | Selector::Location(_) | Selector::Can(_),
         ) => Ok(None),
 
         None => Ok(None),
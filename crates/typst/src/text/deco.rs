@@ -6,7 +6,9 @@ use ecow::{eco_format, EcoString};
 use crate::diag::SourceResult;
 use crate::engine::Engine;
 use crate::foundations::{cast, elem, ty, Content, Fold, Repr, Show, Smart, StyleChain};
-use crate::layout::{Abs, Em, Frame, FrameItem, Length, Point, Size};
+use crate::layout::{
+    Abs, BoxElem, Em, Frame, FrameItem, HAlign, Length, PlaceElem, Point, Size, VAlign,
+};
 use crate::syntax::Span;
 use crate::text::{
     BottomEdge, BottomEdgeMetric, TextElem, TextItem, TopEdge, TopEdgeMetric,
@@ -248,6 +250,24 @@ pub struct StrikeElem {
     #[default(false)]
     pub background: bool,
 
+    /// Content to place on top of the struck through text, centered on it.
+    ///
+    /// This is useful to stamp text as void or to show a replacement for it.
+    /// As the overlay is positioned relative to the struck text, the text is
+    /// put into a [box]($box) and can't break across lines when an overlay is
+    /// set.
+    ///
+    /// ```example
+    /// #strike(
+    ///   overlay: rotate(-10deg, text(red)[*VOID*]),
+    /// )[Pay to the order of Bob]
+    ///
+    /// #strike(
+    ///   overlay: move(dy: -1em, text(blue)[twelve]),
+    /// )[ten] dollars
+    /// ```
+    pub overlay: Option<Content>,
+
     /// The content to strike through.
     #[required]
     pub body: Content,
@@ -256,7 +276,7 @@ pub struct StrikeElem {
 impl Show for StrikeElem {
     #[tracing::instrument(name = "StrikeElem::show", skip_all)]
     fn show(&self, _: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
-        Ok(self.body().clone().styled(TextElem::set_deco(Decoration {
+        let mut realized = self.body().clone().styled(TextElem::set_deco(Decoration {
             // Note that we do not support evade option for strikethrough.
             line: DecoLine::Strikethrough {
                 stroke: self.stroke(styles).unwrap_or_default(),
@@ -264,7 +284,17 @@ impl Show for StrikeElem {
                 background: self.background(styles),
             },
             extent: self.extent(styles),
-        })))
+        }));
+
+        if let Some(overlay) = self.overlay(styles) {
+            let placed = PlaceElem::new(overlay)
+                .with_alignment(Smart::Custom(HAlign::Center + VAlign::Horizon))
+                .pack()
+                .spanned(self.span());
+            realized = BoxElem::new().with_body(Some(realized + placed)).pack();
+        }
+
+        Ok(realized)
     }
 }
 
@@ -452,8 +482,13 @@ pub(crate) fn decorate(
 
     for glyph in text.glyphs.iter() {
         let dx = glyph.x_offset.at(text.size) + x;
-        let mut builder =
-            BezPathBuilder::new(font_metrics.units_per_em, text.size, dx.to_raw());
+        let dy = glyph.y_offset.at(text.size);
+        let mut builder = BezPathBuilder::new(
+            font_metrics.units_per_em,
+            text.size,
+            dx.to_raw(),
+            dy.to_raw(),
+        );
 
         let bbox = text.font.ttf().outline_glyph(GlyphId(glyph.id), &mut builder);
         let path = builder.finish();
@@ -463,8 +498,8 @@ pub(crate) fn decorate(
         // Only do the costly segments intersection test if the line
         // intersects the bounding box.
         let intersect = bbox.map_or(false, |bbox| {
-            let y_min = -text.font.to_em(bbox.y_max).at(text.size);
-            let y_max = -text.font.to_em(bbox.y_min).at(text.size);
+            let y_min = -text.font.to_em(bbox.y_max).at(text.size) - dy;
+            let y_max = -text.font.to_em(bbox.y_min).at(text.size) - dy;
             offset >= y_min && offset <= y_max
         });
 
@@ -529,15 +564,17 @@ struct BezPathBuilder {
     units_per_em: f64,
     font_size: Abs,
     x_offset: f64,
+    y_offset: f64,
 }
 
 impl BezPathBuilder {
-    fn new(units_per_em: f64, font_size: Abs, x_offset: f64) -> Self {
+    fn new(units_per_em: f64, font_size: Abs, x_offset: f64, y_offset: f64) -> Self {
         Self {
             path: BezPath::new(),
             units_per_em,
             font_size,
             x_offset,
+            y_offset,
         }
     }
 
@@ -546,7 +583,7 @@ impl BezPathBuilder {
     }
 
     fn p(&self, x: f32, y: f32) -> kurbo::Point {
-        kurbo::Point::new(self.s(x) + self.x_offset, -self.s(y))
+        kurbo::Point::new(self.s(x) + self.x_offset, -self.s(y) - self.y_offset)
     }
 
     fn s(&self, v: f32) -> f64 {
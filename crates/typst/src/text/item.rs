@@ -49,6 +49,8 @@ pub struct Glyph {
     pub x_advance: Em,
     /// The horizontal offset of the glyph.
     pub x_offset: Em,
+    /// The vertical offset of the glyph, upwards from the baseline.
+    pub y_offset: Em,
     /// The range of the glyph in its item's text.
     pub range: Range<u16>,
     /// The source code location of the text.
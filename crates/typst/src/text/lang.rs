@@ -53,6 +53,104 @@ impl Lang {
             _ => Dir::LTR,
         }
     }
+
+    /// Compute a key that orders strings alphabetically in the language.
+    ///
+    /// Letters with diacritics sort like their base letters unless the
+    /// language treats them as separate letters, like the Swedish "ä", which
+    /// comes after "z". Only then do diacritics and finally case break ties.
+    /// Scripts other than Latin are compared by code point.
+    pub fn collation_key(self, text: &str) -> CollationKey {
+        let mut key = CollationKey::default();
+        for c in text.chars().flat_map(char::to_lowercase) {
+            for (weight, accent) in self.collation_weights(c) {
+                key.primary.push(weight);
+                key.accents.push(accent);
+            }
+        }
+
+        // Lowercase letters come first.
+        key.cases = text.chars().map(char::is_uppercase).collect();
+        key
+    }
+
+    /// The primary weights of a lowercase letter, each with whether it was
+    /// written with a diacritic.
+    fn collation_weights(self, c: char) -> Vec<(u32, bool)> {
+        // Letters that sort after "z" or after their base letter.
+        let tailored: &[char] = match self {
+            Self::SWEDISH | Self::FINNISH => &['å', 'ä', 'ö'],
+            Self::DANISH | Self::BOKMÅL | Self::NYNORSK => &['æ', 'ø', 'å'],
+            _ => &[],
+        };
+
+        let z = u32::from('z') << 2;
+        if let Some(i) = tailored.iter().position(|&t| t == c) {
+            return vec![(z + 1 + i as u32, false)];
+        }
+
+        match (self, c) {
+            (Self::SWEDISH | Self::FINNISH, 'æ') => return vec![(z + 2, true)],
+            (Self::SWEDISH | Self::FINNISH, 'ø') => return vec![(z + 3, true)],
+            (Self::SPANISH, 'ñ') => return vec![((u32::from('n') << 2) + 1, false)],
+            _ => {}
+        }
+
+        let weight = |c: char| u32::from(c) << 2;
+        match c {
+            'ß' => vec![(weight('s'), true), (weight('s'), true)],
+            'æ' => vec![(weight('a'), true), (weight('e'), true)],
+            'œ' => vec![(weight('o'), true), (weight('e'), true)],
+            _ => match fold_diacritic(c) {
+                Some(base) => vec![(weight(base), true)],
+                None => vec![(weight(c), false)],
+            },
+        }
+    }
+}
+
+/// A key that orders strings alphabetically in a language.
+///
+/// Created with [`Lang::collation_key`]. Computing the key once per string is
+/// much cheaper than collating the strings again for every comparison.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct CollationKey {
+    /// The weights of the letters.
+    primary: Vec<u32>,
+    /// Whether each letter was written with a diacritic.
+    accents: Vec<bool>,
+    /// Whether each character is uppercase.
+    cases: Vec<bool>,
+}
+
+/// The base letter of a lowercase Latin letter with a diacritic.
+fn fold_diacritic(c: char) -> Option<char> {
+    const LETTERS: &[(char, &str)] = &[
+        ('a', "àáâãäåāăą"),
+        ('c', "çćĉċč"),
+        ('d', "ďđ"),
+        ('e', "èéêëēĕėęě"),
+        ('g', "ĝğġģ"),
+        ('h', "ĥħ"),
+        ('i', "ìíîïĩīĭįı"),
+        ('j', "ĵ"),
+        ('k', "ķ"),
+        ('l', "ĺļľŀł"),
+        ('n', "ñńņňŉ"),
+        ('o', "òóôõöøōŏő"),
+        ('r', "ŕŗř"),
+        ('s', "śŝşšș"),
+        ('t', "ţťŧț"),
+        ('u', "ùúûüũūŭůűų"),
+        ('w', "ŵ"),
+        ('y', "ýÿŷ"),
+        ('z', "źżž"),
+    ];
+
+    LETTERS
+        .iter()
+        .find(|(_, variants)| variants.contains(c))
+        .map(|&(base, _)| base)
 }
 
 impl FromStr for Lang {
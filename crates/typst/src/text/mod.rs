@@ -14,6 +14,8 @@ mod shift;
 mod smallcaps_;
 mod smartquote;
 mod space;
+#[path = "stats.rs"]
+mod stats_;
 
 pub use self::case::*;
 pub use self::deco::*;
@@ -27,8 +29,12 @@ pub use self::shift::*;
 pub use self::smallcaps_::*;
 pub use self::smartquote::*;
 pub use self::space::*;
+pub use self::stats_::*;
 
+use std::collections::HashMap;
 use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
 
 use ecow::{eco_format, EcoString};
 use rustybuzz::{Feature, Tag};
@@ -38,7 +44,8 @@ use crate::diag::{bail, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, category, elem, Args, Array, Cast, Category, Construct, Content, Dict, Fold,
-    NativeElement, Never, PlainText, Repr, Resolve, Scope, Set, Smart, StyleChain, Value,
+    IntoValue, NativeElement, Never, PlainText, Repr, Resolve, Scope, Set, Smart, Str,
+    StyleChain, Value,
 };
 use crate::layout::{Abs, Axis, Dir, Length, Rel};
 use crate::model::ParElem;
@@ -68,6 +75,7 @@ pub(super) fn define(global: &mut Scope) {
     global.define_func::<upper>();
     global.define_func::<smallcaps>();
     global.define_func::<lorem>();
+    global.define_func::<stats>();
 }
 
 /// Customizes the look and layout of text in a variety of ways.
@@ -451,6 +459,34 @@ pub struct TextElem {
     #[ghost]
     pub hyphenate: Hyphenate,
 
+    /// Custom hyphenation points for specific words. These take precedence
+    /// over the hyphenation patterns of the [text language]($text.lang).
+    ///
+    /// Each entry is a word with its permitted hyphenation points marked by
+    /// hyphens. Words without any hyphens are never hyphenated. Words are
+    /// matched case-insensitively. To share a list of exceptions across
+    /// documents, you can keep it in a file and [read]($read) it.
+    ///
+    /// Instead of an array, you can also pass a dictionary that maps language
+    /// codes to arrays of entries. Then, the entries only apply to text in the
+    /// respective language.
+    ///
+    /// ```example
+    /// #set page(width: 90pt)
+    /// #set par(justify: true)
+    /// #set text(hyphenate: true)
+    /// Unprofessionally typeset.
+    ///
+    /// #set text(hyphenations: (
+    ///   "un-pro-fes-sion-al-ly",
+    ///   "typeset",
+    /// ))
+    /// Unprofessionally typeset.
+    /// ```
+    #[borrowed]
+    #[ghost]
+    pub hyphenations: Hyphenations,
+
     /// Whether to apply kerning.
     ///
     /// When enabled, specific letter pairings move closer together or further
@@ -546,6 +582,10 @@ pub struct TextElem {
     /// The width of numbers / figures. When set to `{auto}`, the default
     /// numbers for the font are used.
     ///
+    /// If tabular numbers are requested, but the font doesn't support them,
+    /// Typst widens all digits to the width of the widest one so that they
+    /// still align in columns.
+    ///
     /// ```example
     /// #set text(font: "Noto Sans", 20pt)
     /// #set text(number-width: "proportional")
@@ -600,6 +640,23 @@ pub struct TextElem {
     #[ghost]
     pub features: FontFeatures,
 
+    /// Whether to shape the text with the font's OpenType tables.
+    ///
+    /// Shaping forms ligatures, applies kerning and positions marks. When it
+    /// is disabled, each character is set with the font's default glyph for
+    /// it and advanced by that glyph's width. This keeps the cells of
+    /// monospace content aligned, for instance for ASCII tables in raw blocks
+    /// (`{show raw: set text(shaping: false)}`). Scripts like Arabic or
+    /// Devanagari are not legible without shaping.
+    ///
+    /// ```example
+    /// office \
+    /// #text(shaping: false)[office]
+    /// ```
+    #[default(true)]
+    #[ghost]
+    pub shaping: bool,
+
     /// Content in which all text is styled according to the other arguments.
     #[external]
     #[required]
@@ -962,6 +1019,134 @@ impl Resolve for Hyphenate {
     }
 }
 
+/// Words with custom hyphenation points.
+#[derive(Debug, Clone)]
+pub struct Hyphenations {
+    /// The entries as specified by the user.
+    value: Value,
+    /// The hyphenation points of each lowercased word as character indices,
+    /// keyed by the language the word applies to.
+    words: Arc<HashMap<(Option<Lang>, EcoString), Vec<usize>>>,
+}
+
+impl Hyphenations {
+    /// Create the lookup table for hyphenation entries.
+    fn new(value: Value, entries: Vec<(Option<Lang>, EcoString)>) -> Self {
+        let words = entries
+            .into_iter()
+            .map(|(lang, entry)| {
+                let mut word = EcoString::new();
+                let mut points = vec![];
+                let mut count = 0;
+                for c in entry.chars() {
+                    if c != '-' {
+                        for l in c.to_lowercase() {
+                            word.push(l);
+                            count += 1;
+                        }
+                    } else if count > 0 && points.last() != Some(&count) {
+                        points.push(count);
+                    }
+                }
+                if points.last() == Some(&count) {
+                    points.pop();
+                }
+                ((lang, word), points)
+            })
+            .collect();
+        Self { value, words: Arc::new(words) }
+    }
+
+    /// The custom hyphenation points of a word in the given language as byte
+    /// offsets into the word, if there is an entry for it.
+    ///
+    /// Entries for the specific language take precedence over entries for all
+    /// languages.
+    pub fn get(&self, word: &str, lang: Lang) -> Option<Vec<usize>> {
+        if self.words.is_empty() {
+            return None;
+        }
+
+        // Lowercasing may change the number of characters, so we remember
+        // which character of the word each lowercased character stems from.
+        let mut lower = EcoString::new();
+        let mut offsets = vec![];
+        for (i, c) in word.char_indices() {
+            for l in c.to_lowercase() {
+                lower.push(l);
+                offsets.push(i);
+            }
+        }
+
+        let points = self
+            .words
+            .get(&(Some(lang), lower.clone()))
+            .or_else(|| self.words.get(&(None, lower)))?;
+
+        // Points within the lowercase form of a single character can't be
+        // mapped back to the word.
+        Some(
+            points
+                .iter()
+                .filter(|&&k| offsets[k - 1] != offsets[k])
+                .map(|&k| offsets[k])
+                .collect(),
+        )
+    }
+}
+
+impl Default for Hyphenations {
+    fn default() -> Self {
+        Self::new(Array::new().into_value(), vec![])
+    }
+}
+
+impl PartialEq for Hyphenations {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Hash for Hyphenations {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+cast! {
+    Hyphenations,
+    self => self.value,
+    values: Array => {
+        let entries = hyphenation_entries(None, values.clone())?;
+        Self::new(values.into_value(), entries)
+    },
+    values: Dict => {
+        let mut entries = vec![];
+        for (lang, words) in values.clone() {
+            let lang = lang.parse::<Lang>()?;
+            entries.extend(hyphenation_entries(Some(lang), words.cast()?)?);
+        }
+        Self::new(values.into_value(), entries)
+    },
+}
+
+/// Checks hyphenation entries for the given language.
+fn hyphenation_entries(
+    lang: Option<Lang>,
+    words: Array,
+) -> StrResult<Vec<(Option<Lang>, EcoString)>> {
+    words
+        .into_iter()
+        .map(|v| {
+            let entry: EcoString = v.cast::<Str>()?.into();
+            if entry.is_empty() || entry.chars().any(char::is_whitespace) {
+                bail!("hyphenation entries must be single words");
+            }
+            Ok((lang, entry))
+        })
+        .collect()
+}
+
 /// A stylistic set in a font.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct StylisticSet(u8);
@@ -1150,3 +1335,33 @@ impl Fold for WeightDelta {
         outer + self.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundations::{array, dict};
+
+    #[test]
+    fn test_custom_hyphenation_points() {
+        let hyphenations = array!["hy-phen-ation", "über-tra-gen", "i\u{307}s-tan-bul"]
+            .into_value()
+            .cast::<Hyphenations>()
+            .unwrap();
+        let get = |word| hyphenations.get(word, Lang::ENGLISH);
+        assert_eq!(get("Hyphenation"), Some(vec![2, 6]));
+        assert_eq!(get("Übertragen"), Some(vec![5, 8]));
+        // The dotted capital I lowercases to two characters.
+        assert_eq!(get("İstanbul"), Some(vec![3, 6]));
+        assert_eq!(get("Hyphenations"), None);
+    }
+
+    #[test]
+    fn test_custom_hyphenation_languages() {
+        let hyphenations = dict! { "de" => array!["Wort-art"] }
+            .into_value()
+            .cast::<Hyphenations>()
+            .unwrap();
+        assert_eq!(hyphenations.get("Wortart", Lang::GERMAN), Some(vec![4]));
+        assert_eq!(hyphenations.get("Wortart", Lang::ENGLISH), None);
+    }
+}
@@ -9,11 +9,11 @@ use syntect::highlighting as synt;
 use syntect::parsing::{SyntaxDefinition, SyntaxSet, SyntaxSetBuilder};
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::diag::{At, FileError, SourceResult, StrResult};
+use crate::diag::{bail, At, FileError, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, elem, scope, Args, Array, Bytes, Content, Finalize, Fold, NativeElement,
-    PlainText, Show, Smart, StyleChain, Styles, Synthesize, Value,
+    array, cast, elem, scope, Args, Array, Bytes, Content, Finalize, Fold, IntoValue,
+    NativeElement, PlainText, Show, Smart, StyleChain, Styles, Synthesize, Value,
 };
 use crate::layout::{BlockElem, Em, HAlign};
 use crate::model::Figurable;
@@ -256,6 +256,24 @@ pub struct RawElem {
     #[default(2)]
     pub tab_size: usize,
 
+    /// The lines to display, given as the numbers of the first and the last
+    /// line (both inclusive, starting at one).
+    ///
+    /// Together with [`read`]($read), this lets you show an excerpt of a
+    /// source file that stays in sync with the file itself. The displayed
+    /// lines keep their original line numbers, and the whole text is still
+    /// used for syntax highlighting.
+    ///
+    /// ````example
+    /// #raw(
+    ///   "fn main() {\n    let x = 1;\n    println!(\"{x}\");\n}",
+    ///   lang: "rust",
+    ///   block: true,
+    ///   excerpt: (2, 3),
+    /// )
+    /// ````
+    pub excerpt: Option<RawExcerpt>,
+
     /// The stylized lines of raw text.
     ///
     /// Made accessible for the [`raw.line` element]($raw.line).
@@ -385,6 +403,10 @@ impl Synthesize for RawElem {
             }));
         };
 
+        if let Some(excerpt) = self.excerpt(styles) {
+            seq.retain(|line| excerpt.contains(*line.number()));
+        }
+
         self.push_lines(seq);
 
         Ok(())
@@ -642,6 +664,39 @@ fn to_syn(color: Color) -> synt::Color {
 #[derive(Debug, Default, Clone, PartialEq, Hash)]
 pub struct SyntaxPaths(Vec<EcoString>);
 
+/// An inclusive range of line numbers to display from a raw text.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct RawExcerpt {
+    start: i64,
+    end: i64,
+}
+
+impl RawExcerpt {
+    /// Whether the line with the given number is part of the excerpt.
+    fn contains(self, number: i64) -> bool {
+        (self.start..=self.end).contains(&number)
+    }
+}
+
+cast! {
+    RawExcerpt,
+    self => array![self.start, self.end].into_value(),
+    array: Array => {
+        let mut iter = array.into_iter();
+        let (Some(start), Some(end), None) = (iter.next(), iter.next(), iter.next()) else {
+            bail!("excerpt must contain exactly two line numbers");
+        };
+        let (start, end) = (start.cast::<i64>()?, end.cast::<i64>()?);
+        if start < 1 {
+            bail!("line numbers must be at least one");
+        }
+        if end < start {
+            bail!("excerpt must not end before it starts");
+        }
+        Self { start, end }
+    },
+}
+
 cast! {
     SyntaxPaths,
     self => self.0.into_value(),
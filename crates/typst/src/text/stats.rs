@@ -0,0 +1,92 @@
+use std::ops::AddAssign;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::foundations::{dict, func, Content, Dict};
+
+/// Counts the words and characters in content.
+///
+/// Returns a dictionary with the keys `words` and `characters`. Characters are
+/// counted as grapheme clusters and whitespace is not included. Only the
+/// textual parts of the content are considered, so equations, images and
+/// similar elements do not contribute to the counts. Words joined by hyphens,
+/// like "well-known", count as a single word.
+///
+/// Since the content is not laid out, there is no page count. To check against
+/// a page limit, use the final value of the page counter instead:
+/// `{locate(loc => counter(page).final(loc).first())}`.
+///
+/// # Example
+/// ```example
+/// #let abstract = [
+///   We study the effect of
+///   typesetting on the joy of
+///   reading scientific papers.
+/// ]
+///
+/// #abstract
+/// #let (words, characters) = stats(abstract)
+/// _The abstract has #words words
+/// and #characters characters._
+/// ```
+#[func]
+pub fn stats(
+    /// The content to count words and characters in.
+    body: Content,
+) -> Dict {
+    let stats = TextStats::of(&body.plain_text());
+    dict! {
+        "words" => stats.words,
+        "characters" => stats.characters,
+    }
+}
+
+/// Word and character counts of a piece of text.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct TextStats {
+    /// The number of words, as determined by Unicode word boundaries. Words
+    /// joined by hyphens count as one.
+    pub words: usize,
+    /// The number of grapheme clusters that are not whitespace.
+    pub characters: usize,
+}
+
+impl TextStats {
+    /// Count the words and characters in the text.
+    pub fn of(text: &str) -> Self {
+        Self {
+            words: count_words(text),
+            characters: text
+                .graphemes(true)
+                .filter(|g| !g.chars().all(char::is_whitespace))
+                .count(),
+        }
+    }
+}
+
+/// Count the words in a text, treating hyphenated compounds as one word.
+fn count_words(text: &str) -> usize {
+    let mut count = 0;
+    let mut after_word = false;
+    let mut after_hyphen = false;
+    for segment in text.split_word_bounds() {
+        if segment.chars().any(char::is_alphanumeric) {
+            if !after_hyphen {
+                count += 1;
+            }
+            after_word = true;
+            after_hyphen = false;
+        } else {
+            after_hyphen = after_word && matches!(segment, "-" | "\u{2010}" | "\u{2011}");
+            after_word = false;
+        }
+    }
+    count
+}
+
+impl AddAssign for TextStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.words += rhs.words;
+        self.characters += rhs.characters;
+    }
+}
@@ -0,0 +1,300 @@
+use ecow::EcoString;
+
+use crate::diag::{bail, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{elem, Cast, StyleChain};
+use crate::layout::{
+    Abs, Fragment, Frame, FrameItem, Layout, Length, Point, Regions, Rel, Size,
+};
+use crate::visualize::{Color, Geometry, Paint};
+
+/// A one-dimensional barcode.
+///
+/// The bars are drawn as vector shapes, so they stay sharp at any size and
+/// don't require an external image. The human-readable text is not part of
+/// the barcode and can be added below it as regular text.
+///
+/// # Example
+/// ```example
+/// #barcode("400638133393")
+/// #barcode(kind: "code128", width: 5cm, "Typst")
+/// ```
+#[elem(Layout)]
+pub struct BarcodeElem {
+    /// The data to encode.
+    ///
+    /// For EAN-13 and EAN-8 barcodes, the check digit is computed
+    /// automatically if it is omitted. If it is given, it is validated. Code
+    /// 128 barcodes accept printable ASCII characters.
+    #[required]
+    pub data: EcoString,
+
+    /// The symbology of the barcode.
+    #[default(BarcodeKind::Ean13)]
+    pub kind: BarcodeKind,
+
+    /// The width of the barcode.
+    #[resolve]
+    #[default(Abs::cm(3.7).into())]
+    pub width: Rel<Length>,
+
+    /// The height of the barcode.
+    #[resolve]
+    #[default(Abs::cm(2.3).into())]
+    pub height: Rel<Length>,
+
+    /// The color of the bars.
+    #[default(Color::BLACK.into())]
+    pub fill: Paint,
+}
+
+impl Layout for BarcodeElem {
+    #[tracing::instrument(name = "BarcodeElem::layout", skip_all)]
+    fn layout(
+        &self,
+        _: &mut Engine,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let modules = self.kind(styles).encode(self.data()).at(self.span())?;
+        let size = Size::new(
+            self.width(styles).relative_to(regions.base().x),
+            self.height(styles).relative_to(regions.base().y),
+        );
+
+        if !size.is_finite() {
+            bail!(self.span(), "cannot create barcode with infinite size");
+        }
+
+        let module = size.x / modules.len() as f64;
+        let fill = self.fill(styles);
+        let mut frame = Frame::soft(size);
+
+        // Draw each run of dark modules as a single bar.
+        let mut x = 0;
+        while x < modules.len() {
+            if !modules[x] {
+                x += 1;
+                continue;
+            }
+
+            let start = x;
+            while x < modules.len() && modules[x] {
+                x += 1;
+            }
+
+            let pos = Point::with_x(module * start as f64);
+            let bar = Size::new(module * (x - start) as f64, size.y);
+            let shape = Geometry::Rect(bar).filled(fill.clone());
+            frame.push(pos, FrameItem::Shape(shape, self.span()));
+        }
+
+        Ok(Fragment::frame(frame))
+    }
+}
+
+/// A barcode symbology.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum BarcodeKind {
+    /// An EAN-13 barcode with 12 digits and a check digit, as used for
+    /// retail products and books.
+    Ean13,
+    /// An EAN-8 barcode with 7 digits and a check digit, as used for small
+    /// products.
+    Ean8,
+    /// A Code 128 barcode for printable ASCII text, as used for shipping
+    /// labels. The text is encoded with code set B.
+    Code128,
+}
+
+impl BarcodeKind {
+    /// Encode the data into a sequence of dark (`true`) and light modules.
+    fn encode(self, data: &str) -> StrResult<Vec<bool>> {
+        match self {
+            Self::Ean13 | Self::Ean8 => self.encode_ean(data),
+            Self::Code128 => encode_code128(data),
+        }
+    }
+
+    /// Encode the digits of an EAN barcode.
+    fn encode_ean(self, data: &str) -> StrResult<Vec<bool>> {
+        let len = match self {
+            Self::Ean8 => 8,
+            _ => 13,
+        };
+
+        let mut digits = Vec::with_capacity(len);
+        for c in data.chars() {
+            let Some(digit) = c.to_digit(10) else {
+                bail!("barcode must only contain digits");
+            };
+            digits.push(digit as usize);
+        }
+
+        let check = ean_check_digit(&digits[..digits.len().min(len - 1)]);
+        if digits.len() == len - 1 {
+            digits.push(check);
+        } else if digits.len() != len {
+            bail!("barcode must have {} or {len} digits", len - 1);
+        } else if digits[len - 1] != check {
+            bail!("barcode has an invalid check digit (expected {check})");
+        }
+
+        let mut modules = vec![];
+        let mut push = |pattern: u8, width: usize| {
+            for i in (0..width).rev() {
+                modules.push(pattern >> i & 1 == 1);
+            }
+        };
+
+        // The first digit of an EAN-13 barcode is encoded in the parities of
+        // the left half.
+        let (parity, left, right) = match self {
+            Self::Ean8 => (0, &digits[..4], &digits[4..]),
+            _ => (EAN13_PARITY[digits[0]], &digits[1..7], &digits[7..]),
+        };
+
+        push(0b101, 3);
+        for (i, &digit) in left.iter().enumerate() {
+            let even = parity >> (left.len() - 1 - i) & 1 == 1;
+            let code = if even { EAN_G[digit] } else { !EAN_R[digit] & 0x7f };
+            push(code, 7);
+        }
+        push(0b01010, 5);
+        for &digit in right {
+            push(EAN_R[digit], 7);
+        }
+        push(0b101, 3);
+
+        Ok(modules)
+    }
+}
+
+/// Encode text as a Code 128 barcode using code set B.
+fn encode_code128(data: &str) -> StrResult<Vec<bool>> {
+    let mut values = vec![CODE128_START_B];
+    for c in data.chars() {
+        if !matches!(c, ' '..='~') {
+            bail!("code 128 barcode must only contain printable ASCII characters");
+        }
+        values.push(c as usize - ' ' as usize);
+    }
+
+    // The start symbol has weight 1 and each data symbol is weighted by its
+    // position.
+    let sum: usize = values.iter().enumerate().map(|(i, &v)| i.max(1) * v).sum();
+    values.push(sum % 103);
+
+    let mut modules = vec![];
+    let mut push = |widths: &[u8]| {
+        for (i, &width) in widths.iter().enumerate() {
+            modules.resize(modules.len() + width as usize, i % 2 == 0);
+        }
+    };
+
+    for value in values {
+        push(&CODE128[value]);
+    }
+    push(&CODE128_STOP);
+
+    Ok(modules)
+}
+
+/// Compute the check digit for the digits of an EAN barcode.
+fn ean_check_digit(digits: &[usize]) -> usize {
+    // The digit next to the check digit has weight 3 and the weights then
+    // alternate between 1 and 3.
+    let sum: usize = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| if i % 2 == 0 { 3 * d } else { d })
+        .sum();
+    (10 - sum % 10) % 10
+}
+
+/// The right-hand patterns of EAN digits. The left-hand odd-parity patterns
+/// are their complements.
+const EAN_R: [u8; 10] = [
+    0b1110010, 0b1100110, 0b1101100, 0b1000010, 0b1011100, 0b1001110, 0b1010000,
+    0b1000100, 0b1001000, 0b1110100,
+];
+
+/// The left-hand even-parity patterns of EAN digits, which are the right-hand
+/// patterns in reverse.
+const EAN_G: [u8; 10] = [
+    0b0100111, 0b0110011, 0b0011011, 0b0100001, 0b0011101, 0b0111001, 0b0000101,
+    0b0010001, 0b0001001, 0b0010111,
+];
+
+/// Which digits of the left half use even parity, depending on the first
+/// digit of an EAN-13 barcode.
+const EAN13_PARITY: [u8; 10] = [
+    0b000000, 0b001011, 0b001101, 0b001110, 0b010011, 0b011001, 0b011100, 0b010101,
+    0b010110, 0b011010,
+];
+
+/// The bar and space widths of the Code 128 symbols, starting with a bar.
+#[rustfmt::skip]
+const CODE128: [[u8; 6]; 106] = [
+    [2,1,2,2,2,2], [2,2,2,1,2,2], [2,2,2,2,2,1], [1,2,1,2,2,3], [1,2,1,3,2,2],
+    [1,3,1,2,2,2], [1,2,2,2,1,3], [1,2,2,3,1,2], [1,3,2,2,1,2], [2,2,1,2,1,3],
+    [2,2,1,3,1,2], [2,3,1,2,1,2], [1,1,2,2,3,2], [1,2,2,1,3,2], [1,2,2,2,3,1],
+    [1,1,3,2,2,2], [1,2,3,1,2,2], [1,2,3,2,2,1], [2,2,3,2,1,1], [2,2,1,1,3,2],
+    [2,2,1,2,3,1], [2,1,3,2,1,2], [2,2,3,1,1,2], [3,1,2,1,3,1], [3,1,1,2,2,2],
+    [3,2,1,1,2,2], [3,2,1,2,2,1], [3,1,2,2,1,2], [3,2,2,1,1,2], [3,2,2,2,1,1],
+    [2,1,2,1,2,3], [2,1,2,3,2,1], [2,3,2,1,2,1], [1,1,1,3,2,3], [1,3,1,1,2,3],
+    [1,3,1,3,2,1], [1,1,2,3,1,3], [1,3,2,1,1,3], [1,3,2,3,1,1], [2,1,1,3,1,3],
+    [2,3,1,1,1,3], [2,3,1,3,1,1], [1,1,2,1,3,3], [1,1,2,3,3,1], [1,3,2,1,3,1],
+    [1,1,3,1,2,3], [1,1,3,3,2,1], [1,3,3,1,2,1], [3,1,3,1,2,1], [2,1,1,3,3,1],
+    [2,3,1,1,3,1], [2,1,3,1,1,3], [2,1,3,3,1,1], [2,1,3,1,3,1], [3,1,1,1,2,3],
+    [3,1,1,3,2,1], [3,3,1,1,2,1], [3,1,2,1,1,3], [3,1,2,3,1,1], [3,3,2,1,1,1],
+    [3,1,4,1,1,1], [2,2,1,4,1,1], [4,3,1,1,1,1], [1,1,1,2,2,4], [1,1,1,4,2,2],
+    [1,2,1,1,2,4], [1,2,1,4,2,1], [1,4,1,1,2,2], [1,4,1,2,2,1], [1,1,2,2,1,4],
+    [1,1,2,4,1,2], [1,2,2,1,1,4], [1,2,2,4,1,1], [1,4,2,1,1,2], [1,4,2,2,1,1],
+    [2,4,1,2,1,1], [2,2,1,1,1,4], [4,1,3,1,1,1], [2,4,1,1,1,2], [1,3,4,1,1,1],
+    [1,1,1,2,4,2], [1,2,1,1,4,2], [1,2,1,2,4,1], [1,1,4,2,1,2], [1,2,4,1,1,2],
+    [1,2,4,2,1,1], [4,1,1,2,1,2], [4,2,1,1,1,2], [4,2,1,2,1,1], [2,1,2,1,4,1],
+    [2,1,4,1,2,1], [4,1,2,1,2,1], [1,1,1,1,4,3], [1,1,1,3,4,1], [1,3,1,1,4,1],
+    [1,1,4,1,1,3], [1,1,4,3,1,1], [4,1,1,1,1,3], [4,1,1,3,1,1], [1,1,3,1,4,1],
+    [1,1,4,1,3,1], [3,1,1,1,4,1], [4,1,1,1,3,1], [2,1,1,4,1,2], [2,1,1,2,1,4],
+    [2,1,1,2,3,2],
+];
+
+/// The index of the symbol that starts code set B.
+const CODE128_START_B: usize = 104;
+
+/// The widths of the Code 128 stop symbol, which has an extra bar.
+const CODE128_STOP: [u8; 7] = [2, 3, 3, 1, 1, 1, 2];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(kind: BarcodeKind, data: &str) -> String {
+        let modules = kind.encode(data).unwrap();
+        modules.into_iter().map(|m| if m { '1' } else { '0' }).collect()
+    }
+
+    #[test]
+    fn test_encode_ean13() {
+        let expected = "10100011010100111010111101111010001001011001101010100001010000\
+                        101000010111010010000101100110101";
+        assert_eq!(encode(BarcodeKind::Ean13, "4006381333931"), expected);
+        assert_eq!(encode(BarcodeKind::Ean13, "400638133393"), expected);
+    }
+
+    #[test]
+    fn test_encode_ean8() {
+        let expected =
+            "1010001011010111101111010110111010101001110111001010001001011100101";
+        assert_eq!(encode(BarcodeKind::Ean8, "96385074"), expected);
+    }
+
+    #[test]
+    fn test_encode_code128() {
+        let expected = "11010010000110111000101101101111010100111100101111001001001111\
+                        0100110111101101100011101011";
+        assert_eq!(encode(BarcodeKind::Code128, "Typst"), expected);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::diag::{bail, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{elem, Content, Resolve, StyleChain};
+use crate::layout::{
+    Abs, Axes, Fragment, Frame, FrameItem, Layout, Length, Point, Regions, Size,
+};
+use crate::model::ParbreakElem;
+use crate::text::SpaceElem;
+use crate::visualize::{Geometry, Path, PathItem};
+
+/// A drawing area with its own coordinate system.
+///
+/// All shapes in the canvas are drawn on top of each other, starting from
+/// the canvas' origin, instead of being laid out one after another. This
+/// makes it easy to combine [lines]($line), [polygons]($polygon), and
+/// [paths]($path) into a diagram.
+///
+/// Coordinates in the canvas are given in points and scaled by the canvas'
+/// unit, so that `{1pt}` stands for one unit. The strokes of the shapes are
+/// not scaled. Other content, like text, is neither moved nor scaled and
+/// keeps its natural size at the canvas' origin. The canvas is exactly large
+/// enough to fit its drawing.
+///
+/// # Example
+/// ```example
+/// #canvas(1cm, y-up: true, {
+///   line(end: (3pt, 0pt))
+///   line(end: (0pt, 2pt))
+///   polygon(
+///     fill: blue.lighten(80%),
+///     (0pt, 0pt), (3pt, 1pt), (1pt, 2pt),
+///   )
+/// })
+/// ```
+#[elem(Layout)]
+pub struct CanvasElem {
+    /// The length of one unit.
+    #[required]
+    pub unit: Length,
+
+    /// Whether the y-axis points upwards instead of downwards. When enabled,
+    /// the origin is in the bottom-left corner of the canvas. Content other
+    /// than shapes, like text, keeps its orientation and is aligned with its
+    /// bottom edge at the origin.
+    ///
+    /// ```example
+    /// #canvas(5mm, y-up: true, path(
+    ///   (0pt, 0pt), (1pt, 2pt), (2pt, 1pt), (4pt, 3pt),
+    /// ))
+    /// ```
+    #[default(false)]
+    pub y_up: bool,
+
+    /// The drawing.
+    #[required]
+    pub body: Content,
+}
+
+impl Layout for CanvasElem {
+    #[tracing::instrument(name = "CanvasElem::layout", skip_all)]
+    fn layout(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let unit = self.unit().resolve(styles);
+        if !unit.is_finite() || unit <= Abs::zero() {
+            bail!(self.span(), "canvas unit must be positive");
+        }
+
+        // Lay out each shape on its own so that they all start at the origin.
+        let pod = Regions::one(regions.base(), Axes::splat(false));
+        let mut frames = vec![];
+        layer(engine, self.body(), styles, pod, &mut frames)?;
+
+        // Only layers that consist of shapes are drawn in unit space.
+        let scale = unit.to_pt();
+        let size = frames
+            .iter()
+            .map(|frame| frame.size() * if is_drawing(frame) { scale } else { 1.0 })
+            .fold(Size::zero(), Size::max);
+
+        if !size.is_finite() {
+            bail!(self.span(), "cannot create canvas with infinite size");
+        }
+
+        let transform = Transform { scale, y_up: self.y_up(styles), height: size.y };
+        let mut canvas = Frame::soft(size);
+        for frame in frames {
+            if !is_drawing(&frame) {
+                let y =
+                    if transform.y_up { size.y - frame.height() } else { Abs::zero() };
+                canvas.push_frame(Point::with_y(y), frame);
+                continue;
+            }
+
+            for (pos, item) in frame.items() {
+                let (pos, item) = transform.apply(*pos, item.clone());
+                canvas.push(pos, item);
+            }
+        }
+
+        Ok(Fragment::frame(canvas))
+    }
+}
+
+/// Lay out each shape in the content separately.
+fn layer(
+    engine: &mut Engine,
+    content: &Content,
+    styles: StyleChain,
+    pod: Regions,
+    frames: &mut Vec<Frame>,
+) -> SourceResult<()> {
+    if let Some(children) = content.to_sequence() {
+        for child in children {
+            layer(engine, child, styles, pod, frames)?;
+        }
+    } else if let Some((child, local)) = content.to_styled() {
+        layer(engine, child, styles.chain(local), pod, frames)?;
+    } else if !content.is::<SpaceElem>() && !content.is::<ParbreakElem>() {
+        frames.push(content.layout(engine, styles, pod)?.into_frame());
+    }
+    Ok(())
+}
+
+/// Whether a laid out layer consists only of shapes.
+fn is_drawing(frame: &Frame) -> bool {
+    frame.items().all(|(_, item)| matches!(item, FrameItem::Shape(..)))
+}
+
+/// Maps drawing coordinates to canvas coordinates.
+struct Transform {
+    /// How much to scale the coordinates.
+    scale: f64,
+    /// Whether to flip the y-axis.
+    y_up: bool,
+    /// The height of the canvas.
+    height: Abs,
+}
+
+impl Transform {
+    /// Map a position on the canvas.
+    fn point(&self, point: Point) -> Point {
+        let point = point * self.scale;
+        if self.y_up {
+            Point::new(point.x, self.height - point.y)
+        } else {
+            point
+        }
+    }
+
+    /// Map an offset relative to a position on the canvas.
+    fn offset(&self, offset: Point) -> Point {
+        let offset = offset * self.scale;
+        if self.y_up {
+            Point::new(offset.x, -offset.y)
+        } else {
+            offset
+        }
+    }
+
+    /// Map a shape and its position.
+    fn apply(&self, pos: Point, item: FrameItem) -> (Point, FrameItem) {
+        let mut pos = self.point(pos);
+        let FrameItem::Shape(mut shape, span) = item else { return (pos, item) };
+        shape.geometry = match shape.geometry {
+            Geometry::Line(delta) => Geometry::Line(self.offset(delta)),
+            Geometry::Rect(size) => {
+                let size = size * self.scale;
+                if self.y_up {
+                    pos.y -= size.y;
+                }
+                Geometry::Rect(size)
+            }
+            Geometry::Path(path) => Geometry::Path(self.path(path)),
+        };
+        (pos, FrameItem::Shape(shape, span))
+    }
+
+    /// Map the points of a path.
+    fn path(&self, path: Path) -> Path {
+        Path(
+            path.0
+                .into_iter()
+                .map(|item| match item {
+                    PathItem::MoveTo(p) => PathItem::MoveTo(self.offset(p)),
+                    PathItem::LineTo(p) => PathItem::LineTo(self.offset(p)),
+                    PathItem::CubicTo(a, b, c) => {
+                        PathItem::CubicTo(self.offset(a), self.offset(b), self.offset(c))
+                    }
+                    PathItem::ClosePath => PathItem::ClosePath,
+                })
+                .collect(),
+        )
+    }
+}
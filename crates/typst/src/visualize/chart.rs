@@ -0,0 +1,500 @@
+use std::f64::consts::{FRAC_PI_2, TAU};
+
+use crate::diag::{bail, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    array, cast, elem, repr, Array, Cast, Content, IntoValue, NativeElement, Resolve,
+    StyleChain, Value,
+};
+use crate::layout::{
+    Abs, Axes, Em, Fragment, Frame, FrameItem, Layout, Length, Point, Regions, Rel, Size,
+};
+use crate::text::TextElem;
+use crate::visualize::{ellipse, Color, FixedStroke, Geometry, Paint, Path};
+
+/// A chart that visualizes numeric data.
+///
+/// Each positional argument is a series of data points. A data point is
+/// either a number, in which case its position on the x-axis is given by its
+/// index, or an array with an x and a y coordinate. Bar and pie charts only
+/// support plain numbers.
+///
+/// The chart fills the given width and height, including its axes, tick
+/// labels, and legend. For more elaborate plots, have a look at the
+/// [packages]($packages).
+///
+/// # Example
+/// ```example
+/// #chart(
+///   kind: "bar",
+///   labels: ([Q1], [Q2], [Q3]),
+///   legend: ([2022], [2023]),
+///   (12, 18, 9),
+///   (15, 21, 14),
+/// )
+///
+/// #chart(
+///   kind: "pie",
+///   height: 3cm,
+///   labels: ([Rent], [Food], [Other]),
+///   (900, 400, 250),
+/// )
+/// ```
+#[elem(Layout)]
+pub struct ChartElem {
+    /// The kind of chart.
+    ///
+    /// ```example
+    /// #chart(kind: "line", height: 3cm, (1, 4, 2, 5))
+    /// #chart(kind: "scatter", height: 3cm, ((0, 1), (1.5, 3), (4, 2)))
+    /// ```
+    #[default(ChartKind::Bar)]
+    pub kind: ChartKind,
+
+    /// The width of the chart, including its axes and legend.
+    #[resolve]
+    #[default(Rel::one())]
+    pub width: Rel<Length>,
+
+    /// The height of the chart, including its axes and legend.
+    #[resolve]
+    #[default(Abs::cm(5.0).into())]
+    pub height: Rel<Length>,
+
+    /// Labels for the data points on the x-axis. For pie charts, these label
+    /// the slices in the legend.
+    pub labels: Vec<Content>,
+
+    /// Names for the series, shown in a legend below the chart.
+    pub legend: Vec<Content>,
+
+    /// The colors of the series or, for pie charts, of the slices. If there
+    /// are more series than colors, the colors are repeated.
+    #[default(vec![
+        Color::BLUE.into(),
+        Color::ORANGE.into(),
+        Color::GREEN.into(),
+        Color::RED.into(),
+        Color::PURPLE.into(),
+        Color::TEAL.into(),
+    ])]
+    pub fill: Vec<Paint>,
+
+    /// The data series.
+    #[variadic]
+    pub series: Vec<ChartSeries>,
+}
+
+impl Layout for ChartElem {
+    #[tracing::instrument(name = "ChartElem::layout", skip_all)]
+    fn layout(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let size = Size::new(
+            self.width(styles).relative_to(regions.base().x),
+            self.height(styles).relative_to(regions.base().y),
+        );
+
+        if !size.is_finite() {
+            bail!(self.span(), "cannot create chart with infinite size");
+        }
+
+        let kind = self.kind(styles);
+        let series = self.series();
+        if series.iter().all(|s| s.0.is_empty()) {
+            bail!(self.span(), "chart must contain data");
+        }
+
+        let fill = self.fill(styles);
+        if fill.is_empty() {
+            bail!(self.span(), "chart must have at least one fill");
+        }
+
+        if matches!(kind, ChartKind::Bar | ChartKind::Pie)
+            && series.iter().any(|s| s.0.iter().any(|(x, _)| x.is_some()))
+        {
+            let name = if kind == ChartKind::Bar { "bar" } else { "pie" };
+            bail!(self.span(), "{name} charts only support plain numbers");
+        }
+
+        let mut chart = Chart {
+            engine,
+            styles,
+            elem: self,
+            fill,
+            frame: Frame::soft(size),
+            em: TextElem::size_in(styles),
+        };
+
+        let legend = match kind {
+            ChartKind::Pie => self.labels(styles),
+            _ => self.legend(styles),
+        };
+
+        let legend_height = chart.legend(&legend)?;
+        let area = Size::new(size.x, size.y - legend_height).max(Size::zero());
+        match kind {
+            ChartKind::Pie => chart.pie(area)?,
+            _ => chart.plot(kind, area)?,
+        }
+
+        Ok(Fragment::frame(chart.frame))
+    }
+}
+
+/// The kind of a chart.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum ChartKind {
+    /// Bars grouped by data point.
+    Bar,
+    /// Lines connecting the data points.
+    Line,
+    /// Dots at the data points.
+    Scatter,
+    /// A circle divided into slices for the values of the first series.
+    Pie,
+}
+
+/// A series of data points in a chart, with an optional x coordinate.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct ChartSeries(pub Vec<(Option<f64>, f64)>);
+
+cast! {
+    ChartSeries,
+    self => self.0
+        .into_iter()
+        .map(|(x, y)| match x {
+            Some(x) => array![x, y].into_value(),
+            None => y.into_value(),
+        })
+        .collect::<Array>()
+        .into_value(),
+    array: Array => Self(array
+        .into_iter()
+        .map(|v| match v {
+            Value::Array(point) => match point.as_slice() {
+                [x, y] => Ok((Some(x.clone().cast()?), y.clone().cast()?)),
+                _ => bail!("data points must be numbers or pairs of numbers"),
+            },
+            v => Ok((None, v.cast()?)),
+        })
+        .collect::<StrResult<_>>()?),
+}
+
+/// Lays out the parts of a chart.
+struct Chart<'a, 'v, 'e> {
+    engine: &'a mut Engine<'v>,
+    styles: StyleChain<'a>,
+    elem: &'e ChartElem,
+    fill: Vec<Paint>,
+    frame: Frame,
+    em: Abs,
+}
+
+impl Chart<'_, '_, '_> {
+    /// The fill for the `i`-th series or slice.
+    fn fill(&self, i: usize) -> Paint {
+        self.fill[i % self.fill.len()].clone()
+    }
+
+    /// Lay out a label at its natural size.
+    fn label(&mut self, content: Content) -> SourceResult<Frame> {
+        let pod = Regions::one(Axes::splat(Abs::inf()), Axes::splat(false));
+        Ok(content.layout(self.engine, self.styles, pod)?.into_frame())
+    }
+
+    /// Lay out a number as a label.
+    fn number(&mut self, value: f64, step: f64) -> SourceResult<Frame> {
+        let digits = (-step.log10().floor()).max(0.0) as u8;
+        self.label(TextElem::packed(repr::format_float(value, Some(digits), "")))
+    }
+
+    /// Add a filled shape to the chart.
+    fn push(&mut self, pos: Point, geometry: Geometry, fill: Paint) {
+        let shape = geometry.filled(fill);
+        self.frame.push(pos, FrameItem::Shape(shape, self.elem.span()));
+    }
+
+    /// Add a line to the chart.
+    fn line(&mut self, from: Point, to: Point, stroke: FixedStroke) {
+        let shape = Geometry::Line(to - from).stroked(stroke);
+        self.frame.push(from, FrameItem::Shape(shape, self.elem.span()));
+    }
+
+    /// Lay out the legend centered at the bottom and return its height.
+    fn legend(&mut self, entries: &[Content]) -> SourceResult<Abs> {
+        if entries.is_empty() {
+            return Ok(Abs::zero());
+        }
+
+        let swatch = Em::new(0.6).resolve(self.styles);
+        let gap = Em::new(0.3).resolve(self.styles);
+        let mut labels = vec![];
+        for entry in entries {
+            labels.push(self.label(entry.clone())?);
+        }
+
+        let height = labels.iter().map(Frame::height).max().unwrap_or_default();
+        let width = labels.iter().map(|label| swatch + gap + label.width()).sum::<Abs>()
+            + 3.0 * gap * (labels.len() - 1) as f64;
+
+        let top = self.frame.height() - height;
+        let mut x = (self.frame.width() - width).max(Abs::zero()) / 2.0;
+        for (i, label) in labels.into_iter().enumerate() {
+            let y = top + (height - swatch) / 2.0;
+            self.push(
+                Point::new(x, y),
+                Geometry::Rect(Size::splat(swatch)),
+                self.fill(i),
+            );
+            x += swatch + gap;
+            let label_width = label.width();
+            self.frame
+                .push_frame(Point::new(x, top + height - label.height()), label);
+            x += label_width + 3.0 * gap;
+        }
+
+        Ok(height + 2.0 * gap)
+    }
+
+    /// Lay out a pie chart for the first series.
+    fn pie(&mut self, area: Size) -> SourceResult<()> {
+        let elem = self.elem;
+        let values: Vec<f64> = elem.series()[0].0.iter().map(|&(_, y)| y).collect();
+        if values.iter().any(|&v| v < 0.0) {
+            bail!(elem.span(), "pie chart values must not be negative");
+        }
+
+        let total: f64 = values.iter().sum();
+        if total <= 0.0 {
+            bail!(elem.span(), "pie chart values must not all be zero");
+        }
+
+        let radius = area.x.min(area.y) / 2.0;
+        let center = Point::new(area.x / 2.0, area.y / 2.0);
+        let mut angle = -FRAC_PI_2;
+        for (i, value) in values.into_iter().enumerate() {
+            let sweep = value / total * TAU;
+            if sweep > 0.0 {
+                let path = slice(center, radius, angle, angle + sweep);
+                self.push(Point::zero(), Geometry::Path(path), self.fill(i));
+            }
+            angle += sweep;
+        }
+
+        Ok(())
+    }
+
+    /// Lay out a bar, line, or scatter chart with axes.
+    fn plot(&mut self, kind: ChartKind, area: Size) -> SourceResult<()> {
+        let elem = self.elem;
+        let series = elem.series();
+        let categorical = series.iter().all(|s| s.0.iter().all(|(x, _)| x.is_none()));
+        let count = series.iter().map(|s| s.0.len()).max().unwrap_or(0);
+        let gap = Em::new(0.4).resolve(self.styles);
+
+        // Determine the range of the y-axis. Bars always start at zero.
+        let ys = series.iter().flat_map(|s| s.0.iter().map(|&(_, y)| y));
+        let (mut min, mut max) = bounds(ys);
+        if kind == ChartKind::Bar {
+            min = min.min(0.0);
+            max = max.max(0.0);
+        }
+        let y_axis = Ticks::new(min, max);
+
+        // Determine the range of the x-axis, if it is numeric.
+        let x_axis = (!categorical).then(|| {
+            let xs = series
+                .iter()
+                .flat_map(|s| s.0.iter().enumerate())
+                .map(|(i, &(x, _))| x.unwrap_or(i as f64));
+            let (min, max) = bounds(xs);
+            Ticks::new(min, max)
+        });
+
+        // Lay out the tick labels.
+        let mut y_labels = vec![];
+        for value in y_axis.values() {
+            y_labels.push((value, self.number(value, y_axis.step)?));
+        }
+
+        let mut x_labels = vec![];
+        if let Some(x_axis) = &x_axis {
+            for value in x_axis.values() {
+                x_labels.push((value, self.number(value, x_axis.step)?));
+            }
+        } else {
+            for (i, label) in elem.labels(self.styles).into_iter().enumerate() {
+                x_labels.push((i as f64, self.label(label)?));
+            }
+        }
+
+        // Determine the plot area.
+        let left =
+            y_labels.iter().map(|(_, f)| f.width()).max().unwrap_or_default() + gap;
+        let bottom =
+            x_labels.iter().map(|(_, f)| f.height()).max().unwrap_or_default() + gap;
+        let top = self.em / 2.0;
+        let plot = Size::new(area.x - left, area.y - bottom - top).max(Size::zero());
+        let slot = plot.x / count.max(1) as f64;
+
+        let map_y = |y: f64| top + plot.y * (1.0 - y_axis.fraction(y));
+        let map_x = |i: usize, x: Option<f64>| match (&x_axis, x) {
+            (Some(axis), x) => left + plot.x * axis.fraction(x.unwrap_or(i as f64)),
+            (None, _) => left + slot * (i as f64 + 0.5),
+        };
+
+        // Draw the grid lines and the y-axis labels.
+        let grid = FixedStroke {
+            paint: Color::SILVER.into(),
+            thickness: Abs::pt(0.5),
+            ..FixedStroke::default()
+        };
+        for (value, label) in y_labels {
+            let y = map_y(value);
+            self.line(Point::new(left, y), Point::new(left + plot.x, y), grid.clone());
+            let pos = Point::new(left - gap - label.width(), y - label.height() / 2.0);
+            self.frame.push_frame(pos, label);
+        }
+
+        // Draw the x-axis labels.
+        for (value, label) in x_labels {
+            let x = match &x_axis {
+                Some(axis) => left + plot.x * axis.fraction(value),
+                None => left + slot * (value + 0.5),
+            };
+            let pos = Point::new(x - label.width() / 2.0, top + plot.y + gap);
+            self.frame.push_frame(pos, label);
+        }
+
+        // Draw the data.
+        let zero = map_y(0.0_f64.clamp(y_axis.min, y_axis.max));
+        let width = slot * 0.8 / series.len() as f64;
+        for (s, data) in series.iter().enumerate() {
+            let fill = self.fill(s);
+            let mut path = Path::new();
+            for (i, &(x, y)) in data.0.iter().enumerate() {
+                let point = Point::new(map_x(i, x), map_y(y));
+                match kind {
+                    ChartKind::Bar => {
+                        let x = left + slot * (i as f64 + 0.1) + width * s as f64;
+                        let top = point.y.min(zero);
+                        let size = Size::new(width, (point.y - zero).abs());
+                        self.push(Point::new(x, top), Geometry::Rect(size), fill.clone());
+                    }
+                    ChartKind::Scatter => {
+                        let dot = Size::splat(self.em * 0.35);
+                        let shape = ellipse(dot, Some(fill.clone()), None);
+                        let pos = point - dot.to_point() / 2.0;
+                        self.frame.push(pos, FrameItem::Shape(shape, elem.span()));
+                    }
+                    _ if i == 0 => path.move_to(point),
+                    _ => path.line_to(point),
+                }
+            }
+
+            if kind == ChartKind::Line {
+                let stroke = FixedStroke {
+                    paint: fill,
+                    thickness: Abs::pt(1.5),
+                    ..FixedStroke::default()
+                };
+                let shape = Geometry::Path(path).stroked(stroke);
+                self.frame.push(Point::zero(), FrameItem::Shape(shape, elem.span()));
+            }
+        }
+
+        // Draw the axes.
+        let axis = FixedStroke { thickness: Abs::pt(0.5), ..FixedStroke::default() };
+        let origin = Point::new(left, top + plot.y);
+        self.line(Point::new(left, top), origin, axis.clone());
+        self.line(origin, origin + Point::with_x(plot.x), axis);
+
+        Ok(())
+    }
+}
+
+/// The ticks along an axis.
+struct Ticks {
+    min: f64,
+    max: f64,
+    step: f64,
+}
+
+impl Ticks {
+    /// Determine about five evenly spaced ticks with round values that cover
+    /// the range.
+    fn new(min: f64, max: f64) -> Self {
+        let range = if max > min { max - min } else { min.abs().max(1.0) };
+        let rough = range / 5.0;
+        let magnitude = 10f64.powf(rough.log10().floor());
+        let step = [1.0, 2.0, 5.0, 10.0]
+            .into_iter()
+            .map(|f| f * magnitude)
+            .find(|&step| step >= rough)
+            .unwrap_or(10.0 * magnitude);
+
+        let lo = (min / step).floor() * step;
+        let mut hi = (max / step).ceil() * step;
+        if hi <= lo {
+            hi = lo + step;
+        }
+
+        Self { min: lo, max: hi, step }
+    }
+
+    /// The values at which ticks are placed.
+    fn values(&self) -> impl Iterator<Item = f64> + '_ {
+        let count = ((self.max - self.min) / self.step).round() as usize;
+        (0..=count).map(|i| self.min + i as f64 * self.step)
+    }
+
+    /// Where a value lies on the axis, from 0 at its start to 1 at its end.
+    fn fraction(&self, value: f64) -> f64 {
+        (value - self.min) / (self.max - self.min)
+    }
+}
+
+/// The smallest and largest of some values, ignoring non-finite ones.
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    let (min, max) = values
+        .filter(|v| v.is_finite())
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+            (min.min(v), max.max(v))
+        });
+
+    if min <= max {
+        (min, max)
+    } else {
+        (0.0, 1.0)
+    }
+}
+
+/// Create a pie slice between two angles (in radians, clockwise from the
+/// positive x-axis).
+fn slice(center: Point, radius: Abs, start: f64, end: f64) -> Path {
+    let point =
+        |angle: f64| center + Point::new(radius * angle.cos(), radius * angle.sin());
+
+    let mut path = Path::new();
+    path.move_to(center);
+    path.line_to(point(start));
+
+    // Approximate the arc with one cubic bézier curve per quarter circle.
+    let segments = ((end - start) / FRAC_PI_2).ceil().max(1.0) as usize;
+    let sweep = (end - start) / segments as f64;
+    let k = 4.0 / 3.0 * (sweep / 4.0).tan();
+    for i in 0..segments {
+        let a = start + sweep * i as f64;
+        let b = a + sweep;
+        let tangent =
+            |angle: f64| Point::new(-radius * k * angle.sin(), radius * k * angle.cos());
+        path.cubic_to(point(a) + tangent(a), point(b) - tangent(b), point(b));
+    }
+
+    path.close_path();
+    path
+}
@@ -0,0 +1,168 @@
+use crate::diag::{bail, At, SourceResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    elem, Behave, Behaviour, Content, Label, NativeElement, Resolve, Show, StyleChain,
+};
+use crate::introspection::Locatable;
+use crate::layout::{Abs, Axes, BoxElem, Length, PlaceElem, Point, Rel};
+use crate::util::Numeric;
+use crate::visualize::{Color, LineElem, PolygonElem, Stroke};
+
+/// Marks a position in the document.
+///
+/// An anchor is invisible and takes up no space. Attach a
+/// [label]($label) to it to refer to its position, for instance to draw a
+/// [connector]($connect) to it.
+///
+/// # Example
+/// ```example
+/// Start #anchor() <start> here.
+/// ```
+#[elem(Behave, Locatable, Show)]
+pub struct AnchorElem {}
+
+impl Show for AnchorElem {
+    fn show(&self, _: &mut Engine, _: StyleChain) -> SourceResult<Content> {
+        Ok(Content::empty())
+    }
+}
+
+impl Behave for AnchorElem {
+    fn behaviour(&self) -> Behaviour {
+        Behaviour::Invisible
+    }
+}
+
+/// A line between two labelled positions in the document.
+///
+/// The connector runs from the position of the element with the `from` label
+/// to the position of the element with the `to` label. Both positions are
+/// resolved after layout, so the connector can be placed anywhere on the
+/// page, even before the labelled elements. Connectors between elements on
+/// different pages are not drawn.
+///
+/// # Example
+/// ```example
+/// #connect(<a>, <b>, arrow: true)
+/// This #anchor() <a> points to \
+/// #h(3cm) this. #anchor() <b>
+/// ```
+#[elem(name = "connect", Locatable, Show)]
+pub struct ConnectElem {
+    /// The label of the element to start at.
+    #[required]
+    pub from: Label,
+
+    /// The label of the element to end at.
+    #[required]
+    pub to: Label,
+
+    /// How to [stroke]($stroke) the connector.
+    #[fold]
+    pub stroke: Stroke,
+
+    /// Whether to draw an arrow head at the end of the connector.
+    #[default(false)]
+    pub arrow: bool,
+}
+
+impl Show for ConnectElem {
+    #[tracing::instrument(name = "ConnectElem::show", skip_all)]
+    fn show(&self, engine: &mut Engine, styles: StyleChain) -> SourceResult<Content> {
+        Ok(engine.delayed(|engine| {
+            let span = self.span();
+            let positions = [*self.from(), *self.to()].map(|label| {
+                let elem = engine.introspector.query_label(label).at(span)?;
+                let Some(location) = elem.location() else {
+                    bail!(span, "cannot connect to an element without a location");
+                };
+                Ok(engine.introspector.position(location))
+            });
+
+            let here = engine.introspector.position(self.location().unwrap());
+            if positions
+                .iter()
+                .any(|position| position.as_ref().is_ok_and(|p| p.page != here.page))
+            {
+                return Ok(Content::empty());
+            }
+
+            // Draw relative to the connector's own position.
+            let [from, to] = positions;
+            let from = from?.point - here.point;
+            let to = to?.point - here.point;
+            let stroke = self.stroke(styles);
+            let head = self.arrow(styles).then(|| {
+                let thickness =
+                    stroke.thickness.unwrap_or(Abs::pt(1.0).into()).resolve(styles);
+                Abs::pt(3.0) + thickness * 3.0
+            });
+
+            Ok(BoxElem::new().with_body(Some(draw(from, to, stroke, head))).pack())
+        }))
+    }
+}
+
+/// Draw a connector with an optional arrow head of the given size.
+///
+/// Each shape is placed on its own. Otherwise, the flow would stack them
+/// below each other instead of drawing them at the same origin.
+fn draw(from: Point, to: Point, stroke: Stroke, head: Option<Abs>) -> Content {
+    let paint = stroke.paint.clone().unwrap_or(Color::BLACK.into());
+    let line = LineElem::new()
+        .with_start(point(from))
+        .with_end(Some(point(to)))
+        .with_stroke(stroke);
+
+    let mut drawing = PlaceElem::new(line.pack()).pack();
+    if let Some(size) = head {
+        let head = arrow_head(from, to, size);
+        let polygon = PolygonElem::new(head.into_iter().map(point).collect())
+            .with_fill(Some(paint));
+        drawing += PlaceElem::new(polygon.pack()).pack();
+    }
+
+    drawing
+}
+
+/// The corners of an arrow head of the given size at the end of a line.
+fn arrow_head(from: Point, to: Point, size: Abs) -> [Point; 3] {
+    let delta = to - from;
+    let length = delta.hypot();
+    if length.is_zero() {
+        return [to; 3];
+    }
+
+    let dir = delta / length.to_raw();
+    let normal = Point::new(-dir.y, dir.x);
+    let base = to - dir * size.to_raw();
+    [to, base + normal * (size.to_raw() / 2.0), base - normal * (size.to_raw() / 2.0)]
+}
+
+/// Convert a point into a coordinate for a shape.
+fn point(point: Point) -> Axes<Rel<Length>> {
+    Axes::new(point.x, point.y).map(|v| Rel::from(Length::from(v)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connector_shapes_are_placed_separately() {
+        let to = Point::with_x(Abs::pt(10.0));
+        let drawing = draw(Point::zero(), to, Stroke::default(), Some(Abs::pt(4.0)));
+        let children: Vec<_> = drawing.to_sequence().unwrap().collect();
+        assert_eq!(children.len(), 2);
+        assert!(children.iter().all(|child| child.is::<PlaceElem>()));
+    }
+
+    #[test]
+    fn test_arrow_head_ends_at_target() {
+        let to = Point::with_x(Abs::pt(10.0));
+        let head = arrow_head(Point::zero(), to, Abs::pt(4.0));
+        assert_eq!(head[0], to);
+        assert_eq!(head[1], Point::new(Abs::pt(6.0), Abs::pt(2.0)));
+        assert_eq!(head[2], Point::new(Abs::pt(6.0), Abs::pt(-2.0)));
+    }
+}
@@ -11,24 +11,24 @@ use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
 
 use comemo::{Prehashed, Tracked};
-use ecow::EcoString;
+use ecow::{eco_format, EcoString};
 
-use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::diag::{bail, warning, At, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, elem, func, scope, Bytes, Cast, Content, NativeElement, Resolve, Smart,
     StyleChain,
 };
 use crate::layout::{
-    Abs, Axes, FixedAlign, Fragment, Frame, FrameItem, Layout, Length, Point, Regions,
-    Rel, Size,
+    Abs, Axes, BlockElem, FixedAlign, Fragment, Frame, FrameItem, Layout, Length, Point,
+    Regions, Rel, Sides, Size,
 };
 use crate::loading::Readable;
 use crate::model::Figurable;
 use crate::syntax::Spanned;
-use crate::text::{families, Lang, LocalName, Region};
+use crate::text::{families, Lang, LocalName, Region, TextElem};
 use crate::util::{option_eq, Numeric};
-use crate::visualize::Path;
+use crate::visualize::{Path, Stroke};
 use crate::World;
 
 /// A raster or vector graphic.
@@ -178,14 +178,23 @@ impl Layout for ImageElem {
             }
         };
 
-        let image = Image::with_fonts(
+        let image = match Image::with_fonts(
             data.clone().into(),
             format,
             self.alt(styles),
             engine.world,
             &families(styles).map(|s| s.into()).collect::<Vec<_>>(),
-        )
-        .at(self.span())?;
+        ) {
+            Ok(image) => image,
+            Err(err) if engine.tracer.lenient() => {
+                engine.tracer.warn(warning!(
+                    self.span(), "{err}";
+                    hint: "the image was replaced by a placeholder",
+                ));
+                return self.layout_placeholder(engine, styles, regions, err);
+            }
+            Err(err) => return Err(err).at(self.span()),
+        };
 
         let sizing = Axes::new(self.width(styles), self.height(styles));
         let region = sizing
@@ -244,6 +253,31 @@ impl Layout for ImageElem {
     }
 }
 
+impl ImageElem {
+    /// Lay out a framed box showing why the image could not be loaded.
+    fn layout_placeholder(
+        &self,
+        engine: &mut Engine,
+        styles: StyleChain,
+        regions: Regions,
+        err: EcoString,
+    ) -> SourceResult<Fragment> {
+        let inset = Rel::from(Length::from(Abs::pt(5.0)));
+        BlockElem::new()
+            .with_width(self.width(styles))
+            .with_height(self.height(styles))
+            .with_breakable(false)
+            .with_stroke(Sides::splat(Some(Some(Stroke::default()))))
+            .with_inset(Sides::splat(Some(inset)))
+            .with_body(Some(TextElem::packed(eco_format!(
+                "image could not be loaded: {err}"
+            ))))
+            .pack()
+            .spanned(self.span())
+            .layout(engine, styles, regions)
+    }
+}
+
 impl LocalName for ImageElem {
     fn local_name(lang: Lang, region: Option<Region>) -> &'static str {
         match lang {
@@ -310,7 +344,7 @@ struct Repr {
 }
 
 /// A kind of image.
-#[derive(Hash)]
+#[derive(Clone, Hash)]
 pub enum ImageKind {
     /// A raster image.
     Raster(RasterImage),
@@ -400,6 +434,20 @@ impl Image {
     pub fn kind(&self) -> &ImageKind {
         &self.0.kind
     }
+
+    /// The same image without alternative text.
+    ///
+    /// The decoded data is shared with this image. Exporters can use this to
+    /// encode and embed images that only differ in their alternative text only
+    /// once.
+    pub fn without_alt(&self) -> Self {
+        if self.0.alt.is_none() {
+            return self.clone();
+        }
+
+        let kind = self.0.kind.clone();
+        Self(Arc::new(Prehashed::new(Repr { kind, alt: None })))
+    }
 }
 
 impl Debug for Image {
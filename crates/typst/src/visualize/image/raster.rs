@@ -26,6 +26,10 @@ struct Repr {
 
 impl RasterImage {
     /// Decode a raster image.
+    ///
+    /// Decoding is memoized, so the same data is only decoded again once the
+    /// result was evicted from the cache through `comemo::evict`. The cache is
+    /// bounded by age, not by size.
     #[comemo::memoize]
     pub fn new(data: Bytes, format: RasterFormat) -> StrResult<Self> {
         fn decode_with<'a, T: ImageDecoder<'a>>(
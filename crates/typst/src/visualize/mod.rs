@@ -1,6 +1,10 @@
 //! Drawing and visualization.
 
+mod barcode;
+mod canvas;
+mod chart;
 mod color;
+mod connect;
 mod gradient;
 mod image;
 mod line;
@@ -8,10 +12,15 @@ mod paint;
 mod path;
 mod pattern;
 mod polygon;
+mod qrcode;
 mod shape;
 mod stroke;
 
+pub use self::barcode::*;
+pub use self::canvas::*;
+pub use self::chart::*;
 pub use self::color::*;
+pub use self::connect::*;
 pub use self::gradient::*;
 pub use self::image::*;
 pub use self::line::*;
@@ -19,6 +28,7 @@ pub use self::paint::*;
 pub use self::path::*;
 pub use self::pattern::*;
 pub use self::polygon::*;
+pub use self::qrcode::*;
 pub use self::shape::*;
 pub use self::stroke::*;
 
@@ -47,4 +57,10 @@ pub(super) fn define(global: &mut Scope) {
     global.define_elem::<CircleElem>();
     global.define_elem::<PolygonElem>();
     global.define_elem::<PathElem>();
+    global.define_elem::<CanvasElem>();
+    global.define_elem::<AnchorElem>();
+    global.define_elem::<ConnectElem>();
+    global.define_elem::<QrCodeElem>();
+    global.define_elem::<BarcodeElem>();
+    global.define_elem::<ChartElem>();
 }
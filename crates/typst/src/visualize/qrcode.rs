@@ -0,0 +1,165 @@
+use ecow::EcoString;
+use qrcodegen::{QrCode, QrCodeEcc};
+
+use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{elem, Cast, StyleChain};
+use crate::layout::{
+    Abs, Fragment, Frame, FrameItem, Layout, Length, Point, Regions, Rel, Size,
+};
+use crate::visualize::{Color, Geometry, Paint};
+
+/// A QR code.
+///
+/// The code is drawn as vector shapes, so it stays sharp at any size and
+/// doesn't require an external image. Leave some empty space around the code
+/// so that it can be reliably scanned.
+///
+/// # Example
+/// ```example
+/// #qrcode("https://typst.app", size: 2cm)
+/// ```
+#[elem(name = "qrcode", title = "QR Code", Layout)]
+pub struct QrCodeElem {
+    /// The text to encode.
+    #[required]
+    pub data: EcoString,
+
+    /// The width and height of the code.
+    #[resolve]
+    #[default(Abs::cm(2.5).into())]
+    pub size: Rel<Length>,
+
+    /// How much of the code may be damaged while still being readable.
+    /// Higher levels make the code denser. If the data fits into a code of
+    /// the same size with a higher level, that level is used instead.
+    ///
+    /// ```example
+    /// #qrcode("Typst", size: 2cm, error-correction: "low")
+    /// #qrcode("Typst", size: 2cm, error-correction: "high")
+    /// ```
+    #[default(QrErrorCorrection::Medium)]
+    pub error_correction: QrErrorCorrection,
+
+    /// The color of the dark modules.
+    #[default(Color::BLACK.into())]
+    pub fill: Paint,
+}
+
+impl Layout for QrCodeElem {
+    #[tracing::instrument(name = "QrCodeElem::layout", skip_all)]
+    fn layout(
+        &self,
+        _: &mut Engine,
+        styles: StyleChain,
+        regions: Regions,
+    ) -> SourceResult<Fragment> {
+        let modules =
+            encode(self.data(), self.error_correction(styles)).at(self.span())?;
+        let size = self.size(styles).relative_to(regions.base().x);
+        if !size.is_finite() {
+            bail!(self.span(), "cannot create QR code with infinite size");
+        }
+
+        let n = modules.len();
+        let module = size / n as f64;
+        let fill = self.fill(styles);
+        let mut frame = Frame::soft(Size::splat(size));
+
+        // Draw each run of dark modules in a row as a single rectangle.
+        for (y, row) in modules.iter().enumerate() {
+            let mut x = 0;
+            while x < n {
+                if !row[x] {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                while x < n && row[x] {
+                    x += 1;
+                }
+
+                let pos = Point::new(module * start as f64, module * y as f64);
+                let rect = Size::new(module * (x - start) as f64, module);
+                let shape = Geometry::Rect(rect).filled(fill.clone());
+                frame.push(pos, FrameItem::Shape(shape, self.span()));
+            }
+        }
+
+        Ok(Fragment::frame(frame))
+    }
+}
+
+/// Encode text into the rows of dark (`true`) and light modules of a QR code.
+fn encode(data: &str, error_correction: QrErrorCorrection) -> StrResult<Vec<Vec<bool>>> {
+    let ecc = match error_correction {
+        QrErrorCorrection::Low => QrCodeEcc::Low,
+        QrErrorCorrection::Medium => QrCodeEcc::Medium,
+        QrErrorCorrection::Quartile => QrCodeEcc::Quartile,
+        QrErrorCorrection::High => QrCodeEcc::High,
+    };
+
+    let Ok(code) = QrCode::encode_text(data, ecc) else {
+        bail!("data is too long for a QR code");
+    };
+
+    let n = code.size();
+    Ok((0..n)
+        .map(|y| (0..n).map(|x| code.get_module(x, y)).collect())
+        .collect())
+}
+
+/// How much of a QR code may be damaged while still being readable.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Cast)]
+pub enum QrErrorCorrection {
+    /// About 7% of the code may be damaged.
+    Low,
+    /// About 15% of the code may be damaged.
+    Medium,
+    /// About 25% of the code may be damaged.
+    Quartile,
+    /// About 30% of the code may be damaged.
+    High,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_qrcode() {
+        // The level is raised to high because the data fits into the same
+        // version with it.
+        let expected = [
+            "111111101010001111111",
+            "100000100010001000001",
+            "101110100100101011101",
+            "101110101011101011101",
+            "101110100100001011101",
+            "100000100110001000001",
+            "111111101010101111111",
+            "000000000010000000000",
+            "001011101100110001001",
+            "100111011011010001101",
+            "111101100100010011111",
+            "111111011000111000010",
+            "011011100001001011011",
+            "000000001010111101000",
+            "111111100101101100111",
+            "100000101001101110000",
+            "101110101111011101011",
+            "101110100010110101010",
+            "101110101001001011001",
+            "100000100101101100010",
+            "111111100111101111011",
+        ];
+
+        let modules = encode("Typst", QrErrorCorrection::Medium).unwrap();
+        let rows: Vec<String> = modules
+            .iter()
+            .map(|row| row.iter().map(|&m| if m { '1' } else { '0' }).collect())
+            .collect();
+        assert_eq!(rows, expected);
+    }
+}
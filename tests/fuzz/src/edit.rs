@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use typst::syntax::{parse, Source, Span};
+
+fuzz_target!(|input: (&str, usize, usize, &str)| {
+    let (text, start, end, replacement) = input;
+    let (start, end) = (start.min(end), start.max(end));
+    if end > text.len() || !text.is_char_boundary(start) || !text.is_char_boundary(end) {
+        return;
+    }
+
+    // Incremental reparsing must yield the same tree as parsing from scratch.
+    let mut source = Source::detached(text);
+    source.edit(start..end, replacement);
+    let mut found = source.root().clone();
+    let mut expected = parse(source.text());
+    found.synthesize(Span::detached());
+    expected.synthesize(Span::detached());
+    assert!(found == expected, "incremental reparse differs from full parse");
+});
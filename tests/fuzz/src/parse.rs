@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use typst::syntax::parse;
+
+fuzz_target!(|text: &str| {
+    std::hint::black_box(parse(text));
+});
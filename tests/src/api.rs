@@ -0,0 +1,518 @@
+//! Tests for the Rust API around compilation.
+
+use std::fs;
+use std::sync::{Arc, Mutex};
+
+use comemo::Prehashed;
+use typst::diag::{FileError, FileResult};
+use typst::eval::{Progress, Tracer};
+use typst::foundations::{
+    Bytes, Content, Datetime, Label, NativeElement, Selector, Value,
+};
+use typst::layout::{Abs, Frame, FrameItem, Point};
+use typst::model::{Document, HeadingElem};
+use typst::syntax::{FileId, PackageSpec, Source, Span, VirtualPath};
+use typst::text::{Font, FontBook};
+use typst::visualize::{Color, Geometry, Shape};
+use typst::{Library, World, WorldExt};
+use typst_pdf::PreflightOptions;
+use walkdir::WalkDir;
+
+const FONT_DIRS: &[&str] = &["../assets/fonts", "fonts"];
+
+#[test]
+fn test_rule_trace_records_each_application_once() {
+    // The query makes the document take more than one layout iteration.
+    let world = TestWorld::new(
+        "#show heading: it => it.body\n\
+         = A\n\
+         = B\n\
+         #locate(loc => query(heading, loc).len())",
+    );
+
+    let mut tracer = Tracer::new();
+    tracer.set_trace_rules(true);
+    typst::compile(&world, &mut tracer).unwrap();
+
+    let rules = tracer.rules();
+    let selector = Selector::Elem(HeadingElem::elem(), None);
+    assert_eq!(rules.len(), 2);
+    assert!(rules.iter().all(|rule| rule.selector.as_ref() == Some(&selector)));
+    assert_eq!(world.text(rules[0].rule), "it => it.body");
+    assert_eq!(world.text(rules[0].target), "= A");
+    assert_eq!(world.text(rules[1].target), "= B");
+}
+
+#[test]
+fn test_balanced_columns_keep_locations() {
+    // Equal elements are told apart by their disambiguator, which must not
+    // be advanced by the attempts of balancing.
+    let disambiguators = |balance: bool| {
+        let document = compile(&format!(
+            "#let h = heading[A]\n\
+             #columns(2, balance: {balance}, h + lorem(100))\n\
+             #h"
+        ));
+        document
+            .introspector
+            .query(&Selector::Elem(HeadingElem::elem(), None))
+            .iter()
+            .map(|elem| elem.location().unwrap().disambiguator)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(disambiguators(true), [0, 1]);
+    assert_eq!(disambiguators(false), [0, 1]);
+}
+
+#[test]
+fn test_rule_trace_is_off_by_default() {
+    let world = TestWorld::new("#show heading: it => it.body\n= A");
+    let mut tracer = Tracer::new();
+    typst::compile(&world, &mut tracer).unwrap();
+    assert!(tracer.rules().is_empty());
+}
+
+#[test]
+fn test_synthesized_tabular_numbers() {
+    // Tuffy has no `tnum` feature and its three is narrower than its eight.
+    let width = |number_width: &str, digits: &str| {
+        let document = compile(&format!(
+            "#set text(font: \"Tuffy\", number-width: {number_width})\n{digits}"
+        ));
+        text_width(&document.pages[0])
+    };
+
+    assert!(width("auto", "333") < width("auto", "888"));
+    assert_eq!(width("\"tabular\"", "333"), width("\"tabular\"", "888"));
+}
+
+#[test]
+fn test_shadowing_is_only_reported_outside_of_packages() {
+    let spec: PackageSpec = "@test/shadow:0.1.0".parse().unwrap();
+    let file = |path| FileId::new(Some(spec.clone()), VirtualPath::new(path));
+    let world = TestWorld::new("#import \"@test/shadow:0.1.0\"\n#let table = (1, 2)")
+        .with_file(
+            file("typst.toml"),
+            "[package]\n\
+             name = \"shadow\"\n\
+             version = \"0.1.0\"\n\
+             entrypoint = \"lib.typ\"",
+        )
+        .with_file(file("lib.typ"), "#let table = (1, 2)");
+
+    let mut tracer = Tracer::new();
+    typst::compile(&world, &mut tracer).unwrap();
+
+    let warnings = tracer.warnings();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].span.id(), Some(world.source.id()));
+}
+
+#[test]
+fn test_diff_bars_are_drawn_per_changed_line() {
+    let document = compile(
+        "#set page(width: 100pt, height: auto)\n\
+         #set diff(bars: true)\n\
+         #set changebar(dx: -5pt)\n\
+         #diff([Kept. ], (insert: lorem(20)), [ Kept.])",
+    );
+
+    let items = flatten(&document.pages[0], Point::zero());
+    let bars: Vec<_> = items
+        .iter()
+        .filter_map(|(pos, item)| match item {
+            FrameItem::Shape(Shape { geometry: Geometry::Line(to), .. }, _)
+                if to.x == Abs::zero() =>
+            {
+                Some((pos.y, pos.y + to.y))
+            }
+            _ => None,
+        })
+        .collect();
+
+    // The baselines of the lines with inserted text.
+    let mut changed: Vec<_> = items
+        .iter()
+        .filter_map(|(pos, item)| match item {
+            FrameItem::Text(text) if text.fill == Color::GREEN.into() => Some(pos.y),
+            _ => None,
+        })
+        .collect();
+    changed.dedup();
+
+    assert!(changed.len() > 1);
+    assert_eq!(bars.len(), changed.len());
+    for (&(top, bottom), &baseline) in bars.iter().zip(&changed) {
+        assert!(top < baseline && baseline < bottom);
+    }
+}
+
+#[test]
+fn test_changebar_spans_content_on_each_page() {
+    let document = compile(
+        "#set page(width: 100pt, height: 80pt, margin: 10pt)\n\
+         #changebar(dx: -4pt, lorem(30))",
+    );
+    assert!(document.pages.len() > 1);
+
+    for page in &document.pages {
+        let items = flatten(page, Point::zero());
+        let bars: Vec<_> = items
+            .iter()
+            .filter_map(|(pos, item)| match item {
+                FrameItem::Shape(Shape { geometry: Geometry::Line(to), .. }, _)
+                    if to.x == Abs::zero() =>
+                {
+                    Some((pos.x, pos.y, pos.y + to.y))
+                }
+                _ => None,
+            })
+            .collect();
+
+        // Each page has a bar for the part of the text on it, starting at the
+        // top of the page's content area.
+        let left: Vec<_> = bars.iter().filter(|bar| bar.0 == Abs::pt(6.0)).collect();
+        assert_eq!(left.len(), 1);
+        let (_, top, bottom) = *left[0];
+        assert_eq!(top, Abs::pt(10.0));
+        assert!(bottom <= Abs::pt(70.0));
+
+        // All text is next to a bar.
+        for (pos, item) in &items {
+            if let FrameItem::Text(_) = item {
+                assert!(bars
+                    .iter()
+                    .any(|&(_, top, bottom)| top < pos.y && pos.y <= bottom));
+            }
+        }
+    }
+
+    // Relative positions are resolved against the width of the content.
+    let document = compile(
+        "#set page(width: 100pt, height: 80pt, margin: 10pt)\n\
+         #changebar(dx: 100% + 4pt)[Right]",
+    );
+    let items = flatten(&document.pages[0], Point::zero());
+    assert!(items.iter().any(|(pos, item)| {
+        matches!(item, FrameItem::Shape(..)) && pos.x == Abs::pt(94.0)
+    }));
+}
+
+#[test]
+fn test_progress_is_reported_for_each_phase() {
+    let other = FileId::new(None, VirtualPath::new("other.typ"));
+    let world = TestWorld::new("#import \"other.typ\": x\n#x")
+        .with_file(other, "#let x = [Hello]");
+
+    let reported = Arc::new(Mutex::new(vec![]));
+    let sink = reported.clone();
+    let mut tracer = Tracer::new();
+    tracer.set_progress(move |progress| sink.lock().unwrap().push(progress));
+    typst::compile(&world, &mut tracer).unwrap();
+
+    assert_eq!(
+        *reported.lock().unwrap(),
+        [
+            Progress::Evaluating(world.source.id()),
+            Progress::Evaluating(other),
+            Progress::Layout { iteration: 0, pages: 0 },
+            Progress::Done { pages: 1 },
+        ]
+    );
+}
+
+#[test]
+fn test_named_destinations_of_labelled_elements() {
+    let document = compile(
+        "= Intro <intro>\n\
+         #figure([A], caption: [B]) <fig>\n\
+         #[Text] <text>\n\
+         #pagebreak()\n\
+         = Twice <twice>\n\
+         = Twice <twice>\n\
+         = Outro <outro>",
+    );
+
+    let dests = document.named_destinations();
+    let labels: Vec<_> = dests.iter().map(|(label, _)| label.as_str()).collect();
+    assert_eq!(labels, ["fig", "intro", "outro"]);
+
+    let (fig, intro, outro) = (dests[0].1, dests[1].1, dests[2].1);
+    assert_eq!((intro.page.get(), fig.page.get(), outro.page.get()), (1, 1, 2));
+    assert!(intro.point.y < fig.point.y);
+
+    let pdf = typst_pdf::pdf(&document, None, None);
+    assert!(contains(&pdf, "/Dests"));
+    assert!(contains(&pdf, "(intro)"));
+    assert!(!contains(&pdf, "(twice)"));
+}
+
+#[test]
+fn test_document_query_returns_positioned_elements() {
+    let document = compile("= A\n#metadata(\"x\") <meta>\n#pagebreak()\n= B");
+
+    let headings = document.query(&Selector::Elem(HeadingElem::elem(), None));
+    let bodies: Vec<_> = headings
+        .iter()
+        .map(|(elem, _)| elem.expect_field_by_name::<Content>("body").plain_text())
+        .collect();
+    assert_eq!(bodies, ["A", "B"]);
+    assert_eq!(headings[0].1.page.get(), 1);
+    assert_eq!(headings[1].1.page.get(), 2);
+
+    let meta = document.query(&Selector::Label(Label::new("meta")));
+    assert_eq!(meta.len(), 1);
+    assert_eq!(meta[0].0.get_by_name("value"), Some(Value::Str("x".into())));
+    assert_eq!(meta[0].1.page.get(), 1);
+
+    assert!(document.query(&Selector::Label(Label::new("missing"))).is_empty());
+}
+
+#[test]
+fn test_document_stats_count_hyphenated_words_once() {
+    let document = compile(
+        "#set page(width: 50pt, height: auto, margin: 0pt)\n\
+         #set text(hyphenate: true)\n\
+         Incomprehensibilities are *well*-known.",
+    );
+
+    // Make sure that the long word was actually hyphenated.
+    let hyphenated =
+        flatten(&document.pages[0], Point::zero()).iter().any(|(_, item)| {
+            matches!(item, FrameItem::Text(text)
+                if text.glyphs.last().is_some_and(|g| g.range.is_empty()))
+        });
+    assert!(hyphenated);
+
+    let stats = document.stats();
+    assert_eq!((stats.words, stats.characters, stats.pages), (3, 35, 1));
+}
+
+#[test]
+fn test_preflight_reports_print_problems() {
+    let world = TestWorld::new(
+        "#set page(width: auto, height: auto)\n\
+         #text(fill: rgb(\"#ff0000\"))[Red]\n\
+         #text(fill: cmyk(0%, 100%, 100%, 0%))[Print]\n\
+         #line(length: 1cm, stroke: 0.1pt)\n\
+         #line(length: 1cm, stroke: 1pt)\n\
+         #image(\"graph.png\", width: 10in)\n\
+         #image(\"graph.png\", width: 2in)",
+    )
+    .with_file(
+        FileId::new(None, VirtualPath::new("graph.png")),
+        fs::read("../assets/files/graph.png").unwrap(),
+    );
+    let document = typst::compile(&world, &mut Tracer::new()).unwrap();
+
+    let messages = |cmyk| {
+        let options = PreflightOptions { cmyk, ..PreflightOptions::default() };
+        typst_pdf::preflight(&document, &options)
+            .iter()
+            .map(|warning| warning.message.clone())
+            .collect::<Vec<_>>()
+    };
+
+    // The image is 1191 pixels wide.
+    let general = [
+        "stroke is only 0.1pt thick",
+        "image has a resolution of only 119 dpi at its rendered size",
+    ];
+    assert_eq!(messages(false), general);
+
+    let mut cmyk = vec!["rgb(\"#ff0000\") is not a CMYK color"];
+    cmyk.extend(general);
+    assert_eq!(messages(true), cmyk);
+}
+
+#[test]
+fn test_form_fields_are_exported_to_acroform() {
+    let document = compile(
+        "#field.text(\"name\", value: \"Anna\")\n\
+         #field.checkbox(\"terms\", checked: true)\n\
+         #field.signature(\"sig\")",
+    );
+
+    let pdf = typst_pdf::pdf(&document, None, None);
+    let pdf = String::from_utf8_lossy(&pdf);
+    assert!(pdf.contains("/AcroForm"));
+    assert_eq!(pdf.matches("/Subtype /Widget").count(), 3);
+    for entry in [
+        "/T (name)",
+        "/FT /Tx",
+        "/V (Anna)",
+        "/DA (/Helv 0 Tf 0 g)",
+        "/T (terms)",
+        "/FT /Btn",
+        "/AS /Yes",
+        "/T (sig)",
+        "/FT /Sig",
+    ] {
+        assert!(pdf.contains(entry), "missing {entry}");
+    }
+}
+
+#[test]
+fn test_formatted_numbers_use_text_language() {
+    let document = compile(
+        "#format-currency(1234.5, \"EUR\")\n\n\
+         #text(lang: \"de\", format-currency(1234.5, \"EUR\"))\n\n\
+         #text(lang: \"de\", format-currency(1234.5, \"EUR\", lang: \"en\"))\n\n\
+         #text(lang: \"de\", format-quantity(2.5, \"kg\"))",
+    );
+
+    assert_eq!(
+        text(&document.pages[0]),
+        "€1,234.50 1.234,50\u{a0}€ €1,234.50 2,5\u{2009}kg"
+    );
+}
+
+#[test]
+fn test_disabled_shaping_maps_each_char_to_a_glyph() {
+    let glyphs = |text: &str| {
+        let document = compile(text);
+        flatten(&document.pages[0], Point::zero())
+            .into_iter()
+            .map(|(_, item)| match item {
+                FrameItem::Text(text) => text.glyphs.len(),
+                _ => 0,
+            })
+            .sum::<usize>()
+    };
+
+    // Libertine has a ligature for "ffi".
+    assert!(glyphs("office") < 6);
+    assert_eq!(glyphs("#set text(shaping: false)\noffice"), 6);
+}
+
+/// All items of a frame and its groups with their absolute positions.
+fn flatten(frame: &Frame, offset: Point) -> Vec<(Point, &FrameItem)> {
+    let mut items = vec![];
+    for (pos, item) in frame.items() {
+        match item {
+            FrameItem::Group(group) => items.extend(flatten(&group.frame, offset + *pos)),
+            _ => items.push((offset + *pos, item)),
+        }
+    }
+    items
+}
+
+/// The text in a frame, with lines separated by spaces.
+fn text(frame: &Frame) -> String {
+    let mut lines: Vec<(Abs, String)> = vec![];
+    for (pos, item) in flatten(frame, Point::zero()) {
+        let FrameItem::Text(text) = item else { continue };
+        match lines.last_mut() {
+            Some((y, line)) if *y == pos.y => line.push_str(&text.text),
+            _ => lines.push((pos.y, text.text.to_string())),
+        }
+    }
+    lines.into_iter().map(|(_, line)| line).collect::<Vec<_>>().join(" ")
+}
+
+/// The total width of all text in a frame.
+fn text_width(frame: &Frame) -> Abs {
+    frame
+        .items()
+        .map(|(_, item)| match item {
+            FrameItem::Group(group) => text_width(&group.frame),
+            FrameItem::Text(text) => text.width(),
+            _ => Abs::zero(),
+        })
+        .sum()
+}
+
+/// Whether the bytes contain the given text.
+fn contains(bytes: &[u8], text: &str) -> bool {
+    bytes.windows(text.len()).any(|window| window == text.as_bytes())
+}
+
+/// Compile a source text into a document, panicking on errors.
+fn compile(text: &str) -> Document {
+    let world = TestWorld::new(text);
+    typst::compile(&world, &mut Tracer::new()).unwrap()
+}
+
+/// A world with a single in-memory source file.
+struct TestWorld {
+    library: Prehashed<Library>,
+    book: Prehashed<FontBook>,
+    fonts: Vec<Font>,
+    source: Source,
+    files: Vec<(FileId, Bytes)>,
+}
+
+impl TestWorld {
+    fn new(text: &str) -> Self {
+        let mut fonts = vec![];
+        for entry in FONT_DIRS
+            .iter()
+            .flat_map(|dir| WalkDir::new(dir).sort_by_file_name())
+            .filter_map(|e| e.ok())
+            .filter(|entry| entry.file_type().is_file())
+        {
+            let data = fs::read(entry.path()).unwrap();
+            fonts.extend(Font::iter(data.into()));
+        }
+
+        let id = FileId::new(None, VirtualPath::new("main.typ"));
+        Self {
+            library: Prehashed::new(Library::build()),
+            book: Prehashed::new(FontBook::from_fonts(&fonts)),
+            fonts,
+            source: Source::new(id, text.into()),
+            files: vec![],
+        }
+    }
+
+    /// Add another file to the world.
+    fn with_file(mut self, id: FileId, data: impl AsRef<[u8]>) -> Self {
+        self.files.push((id, Bytes::from(data.as_ref())));
+        self
+    }
+
+    /// The source text a span points to.
+    fn text(&self, span: Span) -> &str {
+        &self.source.text()[self.range(span).unwrap()]
+    }
+}
+
+impl World for TestWorld {
+    fn library(&self) -> &Prehashed<Library> {
+        &self.library
+    }
+
+    fn book(&self) -> &Prehashed<FontBook> {
+        &self.book
+    }
+
+    fn main(&self) -> Source {
+        self.source.clone()
+    }
+
+    fn source(&self, id: FileId) -> FileResult<Source> {
+        if id == self.source.id() {
+            return Ok(self.source.clone());
+        }
+
+        let text = std::str::from_utf8(&self.file(id)?)?.to_string();
+        Ok(Source::new(id, text))
+    }
+
+    fn file(&self, id: FileId) -> FileResult<Bytes> {
+        self.files
+            .iter()
+            .find(|(file, _)| *file == id)
+            .map(|(_, bytes)| bytes.clone())
+            .ok_or_else(|| FileError::NotFound(id.vpath().as_rootless_path().into()))
+    }
+
+    fn font(&self, index: usize) -> Option<Font> {
+        self.fonts.get(index).cloned()
+    }
+
+    fn today(&self, _: Option<i64>) -> Option<Datetime> {
+        Some(Datetime::from_ymd(1970, 1, 1).unwrap())
+    }
+}
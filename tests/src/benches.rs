@@ -10,7 +10,16 @@ use typst::{Library, World};
 use unscanny::Scanner;
 
 const TEXT: &str = include_str!("../typ/compiler/bench.typ");
-const FONT: &[u8] = include_bytes!("../../assets/fonts/LinLibertine_R.ttf");
+const TEXT_HEAVY: &str = include_str!("../typ/benches/text.typ");
+const TABLE_HEAVY: &str = include_str!("../typ/benches/table.typ");
+const MATH_HEAVY: &str = include_str!("../typ/benches/math.typ");
+const FONTS: &[&[u8]] = &[
+    include_bytes!("../../assets/fonts/LinLibertine_R.ttf"),
+    include_bytes!("../../assets/fonts/LinLibertine_RB.ttf"),
+    include_bytes!("../../assets/fonts/LinLibertine_RI.ttf"),
+    include_bytes!("../../assets/fonts/DejaVuSansMono.ttf"),
+    include_bytes!("../../assets/fonts/NewCMMath-Regular.otf"),
+];
 
 main!(
     bench_decode,
@@ -20,6 +29,9 @@ main!(
     bench_eval,
     bench_compile,
     bench_render,
+    bench_text_heavy,
+    bench_table_heavy,
+    bench_math_heavy,
 );
 
 fn bench_decode(iai: &mut Iai) {
@@ -56,7 +68,7 @@ fn bench_edit(iai: &mut Iai) {
 }
 
 fn bench_eval(iai: &mut Iai) {
-    let world = BenchWorld::new();
+    let world = BenchWorld::new(TEXT);
     let route = typst::engine::Route::default();
     let mut tracer = typst::eval::Tracer::new();
     iai.run(|| {
@@ -66,35 +78,57 @@ fn bench_eval(iai: &mut Iai) {
 }
 
 fn bench_compile(iai: &mut Iai) {
-    let world = BenchWorld::new();
+    let world = BenchWorld::new(TEXT);
     let mut tracer = Tracer::new();
     iai.run(|| typst::compile(&world, &mut tracer));
 }
 
 fn bench_render(iai: &mut Iai) {
-    let world = BenchWorld::new();
+    let world = BenchWorld::new(TEXT);
     let mut tracer = Tracer::new();
     let document = typst::compile(&world, &mut tracer).unwrap();
     iai.run(|| typst_render::render(&document.pages[0], 1.0, Color::WHITE))
 }
 
+fn bench_text_heavy(iai: &mut Iai) {
+    bench_document(iai, TEXT_HEAVY);
+}
+
+fn bench_table_heavy(iai: &mut Iai) {
+    bench_document(iai, TABLE_HEAVY);
+}
+
+fn bench_math_heavy(iai: &mut Iai) {
+    bench_document(iai, MATH_HEAVY);
+}
+
+/// Measure parsing, evaluation and layout of a whole document at once.
+fn bench_document(iai: &mut Iai, text: &str) {
+    let world = BenchWorld::new(text);
+    let mut tracer = Tracer::new();
+    iai.run(|| typst::compile(&world, &mut tracer).unwrap());
+}
+
 struct BenchWorld {
     library: Prehashed<Library>,
     book: Prehashed<FontBook>,
-    font: Font,
+    fonts: Vec<Font>,
     source: Source,
 }
 
 impl BenchWorld {
-    fn new() -> Self {
-        let font = Font::new(FONT.into(), 0).unwrap();
-        let book = FontBook::from_fonts([&font]);
+    fn new(text: &str) -> Self {
+        let fonts: Vec<_> = FONTS
+            .iter()
+            .map(|&data| Font::new(Bytes::from_static(data), 0).unwrap())
+            .collect();
+        let book = FontBook::from_fonts(&fonts);
 
         Self {
             library: Prehashed::new(Library::build()),
             book: Prehashed::new(book),
-            font,
-            source: Source::detached(TEXT),
+            fonts,
+            source: Source::detached(text),
         }
     }
 
@@ -124,8 +158,8 @@ impl World for BenchWorld {
         unimplemented!()
     }
 
-    fn font(&self, _: usize) -> Option<Font> {
-        Some(self.font.clone())
+    fn font(&self, index: usize) -> Option<Font> {
+        self.fonts.get(index).cloned()
     }
 
     fn today(&self, _: Option<i64>) -> Option<Datetime> {
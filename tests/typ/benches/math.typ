@@ -0,0 +1,14 @@
+// A math-heavy document with inline and display equations.
+
+#set page(width: 450pt, height: auto, margin: 1cm)
+#set math.equation(numbering: "(1)")
+
+#for n in range(1, 13) [
+  For $n = #n$, we have $sum_(k=1)^n k = (n(n+1))/2$ and
+  $integral_0^1 x^#n dif x = 1/#(n + 1)$. Furthermore:
+
+  $ f_#n (x) = sum_(i=0)^oo (x^i)/(i!) - sqrt(1 + x^2) / (1 - x) $
+
+  $ mat(1, 2, ..., n; 0, 1, ..., n - 1; dots.v, , dots.down, dots.v; 0, 0, ..., 1)
+    vec(x_1, x_2, dots.v, x_#n) = lr({ a_i mid(|) i in NN }) $
+]
@@ -0,0 +1,22 @@
+// A table-heavy document generated from data.
+
+#set page(width: 450pt, height: auto, margin: 1cm)
+#set text(8pt)
+
+#let rows = range(1, 61).map(i => (
+  str(i),
+  "Item " + str(i),
+  str(calc.rem(i * 37, 101)),
+  str(calc.rem(i * 13, 7) + 1) + " units",
+  [#(calc.rem(i * 37, 101) * (calc.rem(i * 13, 7) + 1))],
+))
+
+#for n in range(3) [
+  #table(
+    columns: (auto, 1fr, auto, auto, auto),
+    align: (right, left, right, right, right),
+    fill: (x, y) => if y == 0 { luma(220) },
+    [*No.*], [*Name*], [*Price*], [*Amount*], [*Total*],
+    ..rows.flatten(),
+  )
+]
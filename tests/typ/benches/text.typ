@@ -0,0 +1,23 @@
+// A text-heavy document with headings, paragraphs and inline markup.
+
+#set page(width: 450pt, height: auto, margin: 1cm)
+#set par(justify: true)
+#set heading(numbering: "1.1")
+
+#let paragraph(n) = [
+  The *#n;th* paragraph discusses _typesetting_ in some depth. Good
+  typography makes long texts pleasant to read: lines of moderate length,
+  consistent spacing and well-chosen hyphenation points let the reader focus
+  on the content instead of the form. This sentence exists mostly to give the
+  line breaker some work with `inline code`, numbers like #(n * 17) and
+  quotes like "this one".
+]
+
+#for i in range(1, 9) [
+  = Chapter #i
+  #for j in range(1, 4) [
+    == Section #j
+    #paragraph(i * 10 + j)
+    #paragraph(i * 10 + j + 1)
+  ]
+]
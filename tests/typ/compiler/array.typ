@@ -151,9 +151,13 @@
 #test("ABCD".split("").slice(1, -1).join("-"), "A-B-C-D")
 
 ---
-// Error: 2-30 array index out of bounds (index: 12, len: 10)
+// Error: 2-30 range index out of bounds (index: 12, len: 10)
 #range(10).slice(9, count: 3)
 
+---
+// Error: 2-36 array index out of bounds (index: 12, len: 10)
+#array.range(10).slice(9, count: 3)
+
 ---
 // Error: 2-24 array index out of bounds (index: -4, len: 3)
 #(1, 2, 3).slice(0, -4)
@@ -248,6 +252,23 @@
 #test((2, 1, 3, -10, -5, 8, 6, -7, 2).sorted(key: x => x), (-10, -7, -5, 1, 2, 2, 3, 6, 8))
 #test((2, 1, 3, -10, -5, 8, 6, -7, 2).sorted(key: x => x * x), (1, 2, 2, 3, -5, 6, -7, 8, -10))
 
+---
+// Test sorting with the alphabetical order of a language.
+#test(("Zucker", "Äpfel", "Apfel").sorted(), ("Apfel", "Zucker", "Äpfel"))
+#test(("Zucker", "Äpfel", "Apfel").sorted(lang: "de"), ("Apfel", "Äpfel", "Zucker"))
+#test(("Zucker", "Äpfel", "Apfel").sorted(lang: "sv"), ("Apfel", "Zucker", "Äpfel"))
+#test(("oso", "ñu", "nube").sorted(lang: "es"), ("nube", "ñu", "oso"))
+#test(("Straße", "Strasse", "Strand").sorted(lang: "de"), ("Strand", "Strasse", "Straße"))
+#test(("b", "B", "a").sorted(lang: "en"), ("a", "b", "B"))
+#test((3, "b", 1).sorted(key: x => str(x), lang: "en"), (1, 3, "b"))
+#test((("Zucker", 1), ("Äpfel", 2), ("Apfel", 3)).sorted(lang: "de"), (("Apfel", 3), ("Äpfel", 2), ("Zucker", 1)))
+#test((("Ober", 2), ("Öl", 1), ("Ober", 1)).sorted(key: x => x, lang: "de"), (("Ober", 1), ("Ober", 2), ("Öl", 1)))
+#test((2, 1).sorted(lang: "de"), (1, 2))
+
+---
+// Error: 2-29 cannot compare string and integer
+#("a", 1).sorted(lang: "en")
+
 ---
 // Error: 12-18 unexpected argument
 #().sorted(x => x)
@@ -89,6 +89,25 @@
 
 #test(out, "AB")
 
+---
+// Test break in equation.
+#let log = ()
+#let out = for i in range(3) {
+  $ #{ log.push("a") } x #break #{ log.push("b") } y $
+}
+
+#test(log, ("a",))
+#test(out.func(), math.equation)
+
+---
+// Test continue in equation.
+#let log = ()
+#for i in range(2) [
+  $ #log.push(i) #continue #log.push("never") $
+]
+
+#test(log, (0, 1))
+
 ---
 // Test continue outside of loop.
 
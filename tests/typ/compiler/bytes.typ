@@ -23,6 +23,16 @@
   bytes("World")
 }), "Hello World")
 
+---
+// Test membership.
+#test(0x65 in bytes("Hello"), true)
+#test(0x66 not in bytes("Hello"), true)
+#test(256 in bytes((0, 1)), false)
+#test(-1 in bytes((255,)), false)
+#test(bytes("ll") in bytes("Hello"), true)
+#test(bytes("lo!") in bytes("Hello"), false)
+#test(bytes(()) in bytes(()), true)
+
 ---
 // Error: 8-14 expected string, array, or bytes, found dictionary
 #bytes((a: 1))
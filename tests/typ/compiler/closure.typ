@@ -163,6 +163,29 @@
   test(greet("Typst", whatever: 10))
 }
 
+---
+// Positional parameters with defaults.
+#{
+  let f(x, y = 2) = (x, y)
+  test(f(1), (1, 2))
+  test(f(1, 3), (1, 3))
+
+  let g(a = 1, b, c = 3) = (a, b, c)
+  test(g(5), (1, 5, 3))
+  test(g(5, 6), (5, 6, 3))
+  test(g(5, 6, 7), (5, 6, 7))
+
+  let h(x = 0, ..rest) = (x, rest.pos())
+  test(h(), (0, ()))
+  test(h(1, 2, 3), (1, (2, 3)))
+
+  test(((x, y = 2, z: 3) => x + y + z)(1), 6)
+}
+
+---
+// Error: 17-18 duplicate parameter: x
+#let f(x, y: 1, x = 2) = none
+
 ---
 // Parameter unpacking.
 #let f((a, b), ..c) = (a, b, c)
@@ -30,14 +30,17 @@
 
 ---
 // Error: 9-16 function `assert` does not contain field `invalid`
+// Hint: 9-16 available fields are eq, ne
 #assert.invalid
 
 ---
 // Error: 7-14 function `enum` does not contain field `invalid`
+// Hint: 7-14 available fields are item
 #enum.invalid
 
 ---
 // Error: 7-14 function `enum` does not contain field `invalid`
+// Hint: 7-14 available fields are item
 #enum.invalid()
 
 ---
@@ -50,12 +53,28 @@
 // Error: 6-13 dictionary does not contain key "invalid"
 #(:).invalid
 
+---
+// Error: 15-16 dictionary does not contain key "c"
+// Hint: 15-16 available fields are a, b
+#(b: 1, a: 2).c
+
+---
+// Error: 7-10 module `calc` does not contain `foo`
+// Hint: 7-10 available fields are abs, acos, asin, atan, atan2, binom, ceil, clamp, cos, cosh, and 30 more
+#calc.foo
+
+---
+// Error: 8-11 length does not contain field "rel"
+// Hint: 8-11 available fields are abs, em
+#(1pt).rel
+
 ---
 // Error: 8-10 cannot access fields on type boolean
 #false.ok
 
 ---
 // Error: 25-28 content does not contain field "fun"
+// Hint: 25-28 available fields are body, bookmarked, level, numbering, outlined, supplement
 #show heading: it => it.fun
 = A
 
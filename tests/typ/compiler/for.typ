@@ -78,6 +78,53 @@
 #test(for v in "" [], none)
 #test(type(for v in "1" []), content)
 
+---
+// Ranges are looped over lazily.
+#let out = ()
+#for i in range(9223372036854775807) {
+  if i >= 3 { break }
+  out.push(i)
+}
+
+#for i in range(10, 0, step: -4) {
+  out.push(i)
+}
+
+#test(out, (0, 1, 2, 10, 6, 2))
+
+// A shadowed range function is still called.
+#{
+  let range(n) = ("a", "b")
+  let out = ()
+  for x in range(5) { out.push(x) }
+  test(out, ("a", "b"))
+}
+
+---
+// Nested patterns.
+#let out = ()
+#for (i, (a, b)) in ((1, 2), (3, 4)).enumerate() {
+  out.push((i, a + b))
+}
+
+#for (k, (x, ..rest)) in (a: (1, 2, 3), b: (4,)) {
+  out.push((k, x, rest))
+}
+
+#test(out, ((0, 3), (1, 7), ("a", 1, (2, 3)), ("b", 4, ())))
+
+// Nested bindings are visible in closures.
+#let fs = ()
+#for (i, (x, y)) in ((1, 2), (3, 4)).enumerate() {
+  fs.push(() => i + x * y)
+}
+
+#test(fs.map(f => f()), (2, 13))
+
+---
+// Error: 23-24 unexpected argument
+#for i in range(1, 2, 3) {}
+
 ---
 // Uniterable expression.
 // Error: 11-15 cannot loop over boolean
@@ -147,6 +147,29 @@ Three
 // Error: 13-14 not enough elements to destructure
 #let (a, b, c) = (1, 2)
 
+---
+// Nested destructuring.
+#let (a, (b, c), ..d) = (1, (2, 3), 4, 5)
+#test((a, b, c, d), (1, 2, 3, (4, 5)))
+
+#let (x: (y, z), w) = (x: (1, 2), w: 3)
+#test((y, z, w), (1, 2, 3))
+
+#let ((a, _), (_, b)) = ((1, 2), (3, 4))
+#test((a, b), (1, 4))
+
+---
+// Error: 14-15 at most one binding per identifier is allowed
+#let (a, (b, a)) = (1, (2, 3))
+
+---
+// Error: 13-14 not enough elements to destructure
+#let (a, (b, c)) = (1, (2,))
+
+---
+// Error: 10-16 cannot destructure integer
+#let (a, (b, c)) = (1, 2)
+
 ---
 // Error: 6-20 not enough elements to destructure
 #let (..a, b, c, d) = (1, 2)
@@ -269,6 +292,49 @@ Three
 // Error: 8-9 expected expression, found underscore
 #{ 1 + _ }
 
+---
+// Shadowing a standard library element.
+// Warning: 6-11 `table` shadows the standard library function of the same name
+// Hint: 6-11 set and show rules cannot refer to the original `table` anymore
+// Hint: 6-11 to shadow it intentionally, derive the new binding from the original, e.g. with `table.with(..)`
+#let table = (1, 2)
+
+// Intentional shadowing and shadowing in nested scopes are fine.
+#let text = text.with(fill: blue)
+#let strong = (body, ..args) => strong(body, ..args, delta: 200)
+#{
+  let box = 1
+}
+
+---
+// Shadowing with a named function, whose body refers to the function itself.
+// Warning: 6-11 `table` shadows the standard library function of the same name
+// Hint: 6-11 set and show rules cannot refer to the original `table` anymore
+// Hint: 6-11 to shadow it intentionally, derive the new binding from the original, e.g. with `table.with(..)`
+#let table(..args) = if args.pos().len() > 1 { table(..args.pos().slice(1)) }
+
+---
+// Names of named arguments and fields don't refer to the original.
+#let d = (strong: 1)
+// Warning: 6-12 `strong` shadows the standard library function of the same name
+// Hint: 6-12 set and show rules cannot refer to the original `strong` anymore
+// Hint: 6-12 to shadow it intentionally, derive the new binding from the original, e.g. with `strong.with(..)`
+#let strong = d.strong
+// Warning: 6-10 `emph` shadows the standard library function of the same name
+// Hint: 6-10 set and show rules cannot refer to the original `emph` anymore
+// Hint: 6-10 to shadow it intentionally, derive the new binding from the original, e.g. with `emph.with(..)`
+#let emph = body => text(body, emph: true)
+
+---
+// Binding the original to its own name opts out of the check.
+#let table = table
+#let table = (1, 2)
+
+---
+// The defaults of a named function's parameters refer to the original.
+#let emph(body, f = emph) = f(body)
+#let strong(body, f: strong) = f(body)
+
 ---
 // Error: 13 expected equals sign
 #let func(x)
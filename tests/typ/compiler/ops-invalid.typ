@@ -122,5 +122,8 @@
 ---
 // Works if we define rect beforehand
 // (since then it doesn't resolve to the standard library version anymore).
+// Warning: 6-10 `rect` shadows the standard library function of the same name
+// Hint: 6-10 set and show rules cannot refer to the original `rect` anymore
+// Hint: 6-10 to shadow it intentionally, derive the new binding from the original, e.g. with `rect.with(..)`
 #let rect = ""
 #(rect = "hi")
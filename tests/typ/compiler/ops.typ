@@ -301,3 +301,35 @@
 #let inc2 = inc.with(y: 2)
 #test(inc2(2), 4)
 #test(inc2(2, y: 4), 6)
+
+// Inspect pre-applied arguments.
+#let (func, args) = add.with(2).with(3).applied()
+#test(func, add)
+#test(args.pos(), (2, 3))
+#test(inc2.applied().args.named(), (y: 2))
+#test(add.applied(), none)
+
+---
+// Test `then` and `compose` methods.
+#let double(x) = 2 * x
+#let inc(x, by: 1) = x + by
+#test(double.then(inc)(3), 7)
+#test(double.compose(inc)(3), 8)
+#test(inc.then(double)(1, by: 2), 6)
+#test(inc.with(by: 5).then(str)(1), "6")
+#test(double.then(inc).then(double)(1), 6)
+#test(repr(double.then(inc)), "double.then(inc)")
+#test(repr(double.compose(inc).then(str)), "inc.then(double).then(str)")
+#test(repr((x => x).then(inc)), "((..) => ..).then(inc)")
+
+// Show rule pipelines.
+#show "A": upper.then(strong)
+A
+
+---
+// Error: 16-17 expected function, found integer
+#(x => x).then(1)
+
+---
+// Error: 2-25 function `pow` cannot be chained as it takes more than one argument
+#calc.abs.then(calc.pow)
@@ -0,0 +1,69 @@
+// Test ranges.
+// Ref: false
+
+---
+// Test the type and representation.
+#test(type(range(3)), range)
+#test(repr(range(3)), "range(0, 3)")
+#test(repr(range(10, step: 3)), "range(0, 12, step: 3)")
+#test(repr(range(5, 2, step: -1)), "range(5, 2, step: -1)")
+#test(repr(range(5, 2)), "range(5, 5)")
+
+---
+// Test equality with ranges and arrays.
+#test(range(0, 10, step: 3), range(0, 11, step: 3))
+#test(range(5, 2), range(0))
+#test(range(3), (0, 1, 2))
+#test((0, 1, 2), range(3))
+#test(range(3) == (0, 1), false)
+#test(hash(range(3)), hash((0, 1, 2)))
+
+---
+// Test the methods.
+#test(range(10).len(), 10)
+#test(range(9223372036854775807).len(), 9223372036854775807)
+#test(range(2, 5).first(), 2)
+#test(range(0, 10, step: 3).last(), 9)
+#test(range(5).at(-1), 4)
+#test(range(5).at(5, default: none), none)
+#test(range(10).slice(2, 6), range(2, 6))
+#test(range(10).slice(4, count: 3), (4, 5, 6))
+#test(range(0, 100, step: 10).slice(-3), (70, 80, 90))
+#test(range(1, 10, step: 2).rev(), (9, 7, 5, 3, 1))
+#test(range(0).rev(), ())
+#test(range(0, 10, step: 3).contains(6), true)
+#test(range(0, 10, step: 3).contains(7), false)
+#test(2.0 in range(5), true)
+#test("a" in range(5), false)
+
+---
+// Error: 2-18 range is empty
+#range(0).first()
+
+---
+// Error: 2-16 range index out of bounds (index: 3, len: 3) and no default value was specified
+#range(3).at(3)
+
+---
+// Ranges behave like arrays elsewhere.
+#test(range(4).map(x => x * 2), (0, 2, 4, 6))
+#test(range(4).sum(), 6)
+#test(array(range(3)), (0, 1, 2))
+#test((..range(3), 3), (0, 1, 2, 3))
+#test(range(2) + (2,), (0, 1, 2))
+#test(range(2) * 2, (0, 1, 0, 1))
+#let (a, b) = range(2)
+#test((a, b), (0, 1))
+
+// Nested ranges are flattened and can be matrix rows.
+#test((range(2), (range(2),)).flatten(), (0, 1, 0, 1))
+#test(math.mat(range(2), (2, 3)).rows, math.mat((0, 1), (2, 3)).rows)
+
+// Mutating a range turns it into an array.
+#let r = range(3)
+#r.push(3)
+#test(r, (0, 1, 2, 3))
+#test(type(r), array)
+
+// The array version is still available.
+#test(type(array.range(3)), array)
@@ -15,9 +15,8 @@
 
 ---
 // Test with unnamed function.
-// Error: 17-18 unknown variable: f
-#let f = (n) => f(n - 1)
-#f(10)
+#let fac = (n) => if n <= 1 { 1 } else { n * fac(n - 1) }
+#test(fac(5), 120)
 
 ---
 // Test capturing with named function.
@@ -31,6 +30,16 @@
 #let f = () => f
 #test(type(f()), int)
 
+// An unnamed function wrapping an existing definition calls that definition.
+#let g(x) = x + 1
+#let g = x => g(x) * 2
+#test(g(1), 4)
+
+// Only a closure bound directly to a name can refer to itself.
+#let g = 10
+#let (g,) = (() => g,)
+#test(type(g()), int)
+
 ---
 // Test redefinition.
 #let f(x) = "hello"
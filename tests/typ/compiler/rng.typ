@@ -0,0 +1,32 @@
+// Test the pseudo-random number generator.
+
+---
+// Ref: false
+// The same seed produces the same numbers.
+#let (_, a) = rng(7).int(0, 100)
+#let (_, b) = rng(7).int(0, 100)
+#test(a, b)
+#test(repr(rng(7)), "rng(7)")
+
+---
+// Ref: false
+// Numbers stay within their bounds.
+#let r = rng(1)
+#for _ in range(50) {
+  let (next, n) = r.int(-3, 3)
+  test(n >= -3 and n < 3, true)
+  let (next, f) = next.float()
+  test(f >= 0.0 and f < 1.0, true)
+  r = next
+}
+
+---
+// Ref: false
+// Shuffling keeps all items.
+#let (_, shuffled) = rng(3).shuffle(range(10))
+#test(shuffled.sorted(), range(10))
+#test(rng(3).shuffle(()).last(), ())
+
+---
+// Error: 2-18 high must be greater than low
+#rng(1).int(5, 5)
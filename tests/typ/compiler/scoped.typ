@@ -0,0 +1,61 @@
+// Test scoping set rules with barriers.
+// Ref: false
+
+---
+#let probe(size) = style(styles => {
+  test(measure(box(width: 1em), styles).width, size)
+})
+
+// The component's set rule doesn't reach the shielded body.
+#let card(body) = scoped({
+  set text(20pt)
+  probe(20pt)
+  scoped.barrier(body)
+})
+
+#set text(15pt)
+#card(probe(15pt))
+
+// Set rules within the barrier still apply.
+#card[#set text(12pt); #probe(12pt)]
+
+---
+#let probe(size) = style(styles => {
+  test(measure(box(width: 1em), styles).width, size)
+})
+
+// Nested components only shield from their own set rules.
+#let inner(body) = scoped({
+  set text(30pt)
+  scoped.barrier(body)
+})
+
+#let outer(body) = scoped({
+  set text(20pt)
+  inner(probe(20pt))
+  inner(scoped.barrier(body))
+})
+
+#set text(15pt)
+#outer(probe(15pt))
+
+---
+// Test inspecting which set rules affect an element.
+// Warning: 2-23 par.leading is set to 5pt here
+// Hint: 2-23 an inner set rule takes precedence
+#set par(leading: 5pt)
+// Warning: 2-23 par.leading is set to 8pt here
+#set par(leading: 8pt)
+#debug-styles(par, "leading")
+
+---
+#scoped({
+  // Warning: 3-24 par.leading is set to 7pt here
+  // Hint: 3-24 this set rule is hidden by a style barrier
+  set par(leading: 7pt)
+  scoped.barrier(debug-styles(par))
+})
+
+---
+// Error: 2-29 element text has no field "sizes"
+#debug-styles(text, "sizes")
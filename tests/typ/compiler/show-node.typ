@@ -79,6 +79,7 @@ Another text.
 
 ---
 // Error: 25-29 content does not contain field "page"
+// Hint: 25-29 available fields are body, bookmarked, level, numbering, outlined, supplement
 #show heading: it => it.page
 = Heading
 
@@ -91,7 +92,7 @@ Hey
 #show upper: it => {}
 
 ---
-// Error: 16-20 expected content or function, found integer
+// Error: 16-20 expected auto, content, or function, found integer
 #show heading: 1234
 = Heading
 
@@ -102,3 +103,10 @@ Hey
 ---
 // Error: 7-25 show is only allowed directly in code and content blocks
 #(1 + show heading: none)
+
+---
+// Test revoking earlier show rules.
+// Ref: false
+#show heading: it => panic("should be revoked")
+#show heading: auto
+= Heading
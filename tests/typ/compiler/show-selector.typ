@@ -41,3 +41,22 @@ the ```rs &mut T``` reference.
 ---
 // Error: 7-35 this selector cannot be used with show
 #show selector(heading).or(figure): none
+
+---
+// Test show rules restricted to a range of the document.
+// Ref: false
+#show heading.after(<appendix>, inclusive: false): it => {
+  test(it.body, [Sources])
+  it
+}
+#show heading.before(<appendix>, inclusive: false): it => {
+  test(it.body, [Introduction])
+  it
+}
+= Introduction
+= Appendix <appendix>
+= Sources
+
+---
+// Error: 7-42 this selector cannot be used with show
+#show selector(regex("a")).after(<start>): none
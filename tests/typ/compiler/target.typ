@@ -0,0 +1,6 @@
+// Test the export target.
+
+---
+// Ref: false
+#test(target(), "paged")
+#test(type(target()), str)
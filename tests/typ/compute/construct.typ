@@ -238,6 +238,37 @@
   "2023 April 29 17 Saturday",
 )
 
+// Test displaying of dates in other languages.
+#test(
+  datetime(year: 2023, month: 3, day: 1)
+    .display("[weekday], [day padding:none]. [month repr:long]", lang: "de"),
+  "Mittwoch, 1. März",
+)
+#test(
+  datetime(year: 2023, month: 2, day: 5)
+    .display("[weekday repr:short] [day] [month repr:short] [year]", lang: "fr"),
+  "dim. 05 févr. 2023",
+)
+
+// Test the default format in other languages.
+#let date = datetime(year: 2023, month: 3, day: 1)
+#test(date.display(lang: "en"), "March 1, 2023")
+#test(date.display(lang: "de"), "1. März 2023")
+#test(date.display(lang: "es"), "1 de marzo de 2023")
+#test(date.display(lang: "ja"), "2023-03-01")
+#test(
+  datetime(year: 2023, month: 3, day: 1, hour: 9, minute: 5, second: 0)
+    .display(lang: "fr"),
+  "1 mars 2023 09:05:00",
+)
+
+// Test taking the language from the styles.
+#set text(lang: "pt")
+#style(styles => test(date.display(styles: styles), "1 de março de 2023"))
+#style(styles => test(date.display(styles: styles, lang: "it"), "1 marzo 2023"))
+#style(styles => test(date.display("[month repr:short]", styles: styles), "mar"))
+#set text(lang: "en")
+
 // Test displaying of times
 #test(datetime(hour: 14, minute: 26, second: 50).display(), "14:26:50")
 #test(datetime(hour: 14, minute: 26, second: 50).display("[hour]"), "14")
@@ -257,6 +288,13 @@
   "2023-04-29 14:26:50",
 )
 
+---
+#let date = datetime(year: 2023, month: 3, day: 1)
+// Warning: 7-62 month and weekday names are not available in language `ja`
+// Hint: 7-62 they are displayed in English instead
+#test(date.display("[weekday] [month repr:long]", lang: "ja"), "Wednesday March")
+
+---
 // Test getting the year/month/day etc. of a datetime
 #let d = datetime(year: 2023, month: 4, day: 29, hour: 14, minute: 26, second: 50)
 #test(d.year(), 2023)
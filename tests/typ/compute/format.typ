@@ -0,0 +1,20 @@
+// Test currency and quantity formatting.
+// Ref: false
+
+---
+// The formatted number is content, since its language is only known where it
+// is placed.
+#test(type(format-currency(1234.5, "USD")), content)
+#test(type(format-quantity(9.81, "m/s²")), content)
+
+---
+// Error: 2-27 currency code must consist of three uppercase letters
+#format-currency(1, "usd")
+
+---
+// Error: 2-34 amount must be a finite number
+#format-currency(calc.nan, "USD")
+
+---
+// Error: 2-32 value must be a finite number
+#format-quantity(calc.inf, "m")
@@ -10,6 +10,42 @@
 #test(repr(ltr), "ltr")
 #test(repr((1, 2, false, )), "(1, 2, false)")
 
+---
+// Test content equality and hashing.
+#let a = [Hello *world*]
+#let b = [Hello *world*]
+#test(a, b)
+#test(hash(a), hash(b))
+#test(hash(1), hash(1.0))
+#test(hash(1pt), hash(1pt + 0%))
+#test(hash((a: 1, b: 2)), hash((b: 2, a: 1)))
+#test(hash(int), hash("integer"))
+#assert.ne(hash([A]), hash([B]))
+#assert.ne(hash((1, 2)), hash((2, 1)))
+#assert.ne(hash("1"), hash(1))
+
+---
+// Test hashing of functions and arguments, which ignores their spans.
+#let f(x, y) = x + y
+#let args(..args) = args
+#test(f.with(1), f.with(1))
+#test(hash(f.with(1)), hash(f.with(1)))
+#test(hash(f.with(1).then(str)), hash(f.with(1).then(str)))
+#test(hash(args(1, b: 2)), hash(args(1, b: 2)))
+#assert.ne(hash(f.with(1)), hash(f.with(2)))
+
+// Closures are only equal to themselves.
+#test(hash(f), hash(f))
+#assert.ne(hash(x => x), hash(x => x))
+
+---
+// Ranges hash like the arrays they compare equal to.
+#test(hash(range(3)), hash((0, 1, 2)))
+#test(hash(range(1, 7, step: 2)), hash((1, 3, 5)))
+#test(hash((range(2), 2)), hash(((0, 1), 2.0)))
+#test(hash(range(5, 2)), hash(()))
+#assert.ne(hash(range(3)), hash(range(1, 4)))
+
 ---
 // Test panic.
 // Error: 7-9 panicked
@@ -103,3 +103,26 @@ This is a normal page. Very normal.
 // Test a page with zero columns.
 // Error: 49-50 number must be positive
 #set page(height: auto, width: 7.05cm, columns: 0)
+
+---
+// Test balancing columns.
+// Ref: false
+#set block(spacing: 0pt)
+#let body = range(4).map(_ => block(height: 10pt)).join()
+#style(styles => {
+  let plain = measure(box(width: 100pt, columns(2, body)), styles)
+  let balanced = measure(box(width: 100pt, columns(2, balance: true, body)), styles)
+  test(plain.height, 40pt)
+  test(balanced.height >= 20pt and balanced.height < 21pt, true)
+})
+
+---
+// Test balancing a paragraph.
+// Ref: false
+#style(styles => {
+  let body = lorem(80)
+  let plain = measure(box(width: 7cm, columns(3, body)), styles)
+  let balanced = measure(box(width: 7cm, columns(3, balance: true, body)), styles)
+  test(balanced.height < plain.height / 2, true)
+  test(balanced.height > plain.height / 4, true)
+})
@@ -0,0 +1,24 @@
+// Test overflow handling of fixed-size boxes.
+// Ref: false
+
+---
+#set box(width: 2.5cm, height: 1.2em, stroke: 0.5pt)
+#box(overflow: "visible")[But, soft! what light through yonder window breaks?]
+#box(overflow: "clip")[But, soft! what light through yonder window breaks?]
+#box(overflow: "scale")[But, soft! what light through yonder window breaks?]
+#box(overflow: "ellipsis")[But, soft! what light through yonder window breaks?]
+
+---
+// Content that fits is left alone.
+#box(width: 3cm, overflow: "scale")[Short]
+#box(width: 3cm, overflow: "ellipsis")[*Short*]
+
+---
+// Not even the ellipsis fits.
+#box(width: 1pt, height: 1pt, overflow: "ellipsis")[Hello]
+#box(height: 1pt, overflow: "ellipsis")[]
+#box(height: 5pt, inset: 3pt, overflow: "scale", rect(width: 2cm, height: 1cm))
+
+---
+// Error: 16-24 expected "visible", "clip", "scale", or "ellipsis"
+#box(overflow: "hidden")
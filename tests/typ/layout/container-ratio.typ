@@ -0,0 +1,21 @@
+// Test boxes with an aspect ratio.
+// Ref: false
+
+---
+#style(styles => {
+  test(measure(box(ratio: 2, width: 10pt), styles), (width: 10pt, height: 5pt))
+  test(measure(box(ratio: 2, height: 10pt), styles), (width: 20pt, height: 10pt))
+  test(measure(box(ratio: 16 / 9, width: 32pt)[Hi], styles), (width: 32pt, height: 18pt))
+})
+
+---
+#set page(width: 100pt)
+#box(ratio: 4 / 3, fill: luma(230))
+
+---
+// Error: 2-15 aspect ratio must be positive
+#box(ratio: 0)
+
+---
+// Error: 2-40 cannot specify width, height, and ratio at once
+#box(width: 1cm, height: 1cm, ratio: 1)
@@ -0,0 +1,19 @@
+// Test the `fit` function.
+// Ref: false
+
+---
+#style(styles => {
+  let r = rect(width: 100pt, height: 20pt)
+  test(measure(fit(width: 50pt, r), styles), (width: 50pt, height: 10pt))
+  test(measure(fit(height: 5pt, r), styles), (width: 25pt, height: 5pt))
+  test(measure(fit(width: 50pt, height: 5pt, r), styles), (width: 25pt, height: 5pt))
+  test(measure(fit(width: 200pt, r), styles), (width: 100pt, height: 20pt))
+  test(measure(fit(width: 200pt, grow: true, r), styles), (width: 200pt, height: 40pt))
+  test(measure(fit(r), styles), (width: 100pt, height: 20pt))
+})
+
+---
+// Text is not broken into lines before fitting.
+#set page(width: 120pt)
+#fit[= A rather long headline]
+#fit(height: 1em)[#lorem(10)]
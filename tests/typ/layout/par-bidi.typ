@@ -9,6 +9,7 @@
 ---
 // Test that consecutive, embedded  LTR runs stay LTR.
 // Here, we have two runs: "A" and italic "B".
+// Warning: 31-32 font family "PT Sans" has no variant with style italic, using style normal instead
 #let content = par[أنت A#emph[B]مطرC]
 #set text(font: ("PT Sans", "Noto Sans Arabic"))
 #text(lang: "ar", content)
@@ -0,0 +1,15 @@
+// Test rendering diffs.
+
+---
+// Ref: false
+#let d = diff([Keep ], (insert: [new]), (delete: [old]))
+#test(d.children.len(), 3)
+#test(d.children.at(1), (insert: [new]))
+
+---
+// Error: 16-44 unexpected key "extra", valid keys are "insert" and "delete"
+#diff([Keep ], (insert: [new], extra: none))
+
+---
+// Error: 7-37 a change must have either an `insert` or a `delete` key
+#diff((insert: [new], delete: [old]))
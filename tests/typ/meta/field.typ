@@ -0,0 +1,22 @@
+// Test form fields.
+
+---
+// Ref: false
+#let name = field.text("name", value: "Anna")
+#test(name.kind, "text")
+#test(name.name, "name")
+#test(name.value, "Anna")
+#test(field.checkbox("terms", checked: true).checked, true)
+#test(field.signature("sig").kind, "signature")
+
+---
+// Error: 31-32 expected boolean, found integer
+#field.checkbox("a", checked: 1)
+
+---
+// Ref: false
+#field.text("name")
+// Warning: 2-20 form field name "name" is used more than once
+// Hint: 2-20 PDF viewers treat fields with the same name as one field
+#field.text("name")
+#field.checkbox("other")
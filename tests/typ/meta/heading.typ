@@ -42,7 +42,9 @@ multiline.
 )
 
 = Heading
+// Warning: 7-14 font family "Roboto" has no variant with weight 700, using weight 400 instead
 ===== Heading 🌍
+// Warning: 20-27 font family "Roboto" has no variant with weight 700, using weight 400 instead
 #heading(level: 5)[Heading]
 
 ---
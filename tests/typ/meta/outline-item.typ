@@ -0,0 +1,25 @@
+// Test outline items.
+
+---
+// Ref: false
+#outline(target: outline.item.where(kind: "exercise"))
+
+#outline.item(kind: "exercise")[Exercise 1]
+#outline.item(kind: "exercise", level: 2)[Exercise 1.1]
+#outline.item[Not an exercise]
+
+#locate(loc => {
+  let items = query(outline.item.where(kind: "exercise"), loc)
+  test(items.map(it => it.level), (1, 2))
+})
+
+---
+// Test that the level and kind are taken from set rules.
+// Ref: false
+#set outline.item(level: 2, kind: "note")
+#outline.item[Note]
+
+#locate(loc => {
+  let items = query(outline.item.where(kind: "note"), loc)
+  test(items.map(it => it.level), (2,))
+})
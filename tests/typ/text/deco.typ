@@ -73,3 +73,12 @@ We can also specify a customized value
 // Test strike background
 #set strike(background: true, stroke: 5pt + red)
 #strike[This is in the background]
+
+---
+// The overlay doesn't affect the size of the struck text.
+// Ref: false
+#style(styles => {
+  let plain = measure(strike[Pay to Bob], styles)
+  let stamped = measure(strike(overlay: text(2em)[VOID])[Pay to Bob], styles)
+  test(stamped.width, plain.width)
+})
@@ -31,6 +31,7 @@ Normal
 *Bold*
 
 #set strong(delta: 150)
+// Warning: 2-8 font family "Linux Libertine" has no variant with weight 550, using weight 400 instead
 *Medium* and *#[*Bold*]*
 
 ---
@@ -16,6 +16,7 @@
 #text(weight: "bold")[Bold]
 
 // Set stretch (not available, matching closest).
+// Warning: 21-30 font family "Linux Libertine" has no variant with stretch 50%, using stretch 100% instead
 #text(stretch: 50%)[Condensed]
 
 // Set font family.
@@ -58,3 +59,9 @@ Emoji: 🐪, 🌋, 🏞
 ---
 // Error: 11-31 unexpected argument: something
 #set text(something: "invalid")
+
+---
+// The requested families are determined for each run of text.
+// Ref: false
+// Warning: 26-30 font family "Roboto" has no variant with weight 700, using weight 400 instead
+A #text(font: "Roboto")[*Bold*] word.
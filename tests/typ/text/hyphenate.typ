@@ -49,3 +49,7 @@ It's a #emph[Tree]beard.
 #set page(width: 60pt)
 #set text(hyphenate: true)
 #h(6pt) networks, the rest.
+
+---
+// Error: 25-39 hyphenation entries must be single words
+#set text(hyphenations: ("two words",))
@@ -77,3 +77,17 @@ The keyword ```rust let```.
 // Unterminated.
 // Error: 1-2:1 unclosed raw text
 `endless
+
+---
+// Test excerpts.
+// Ref: false
+#show raw: it => test(it.lines.map(line => (line.number, line.text)), ((2, "b"), (3, "c")))
+#raw("a\nb\nc\nd", excerpt: (2, 3))
+
+---
+// Error: 23-29 excerpt must not end before it starts
+#raw("a\nb", excerpt: (2, 1))
+
+---
+// Error: 23-27 excerpt must contain exactly two line numbers
+#raw("a\nb", excerpt: (1,))
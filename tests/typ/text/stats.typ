@@ -0,0 +1,19 @@
+// Test word and character counts.
+
+---
+// Ref: false
+#test(stats[], (words: 0, characters: 0))
+#test(stats[Hello, world!], (words: 2, characters: 12))
+#test(stats[*Typst* is _fun_], (words: 3, characters: 10))
+#test(stats[Don't panic.], (words: 2, characters: 11))
+#test(stats[A well-known fact], (words: 3, characters: 15))
+#test(stats[Pre- and post-war], (words: 3, characters: 15))
+
+---
+// Error: 8-10 expected content, found integer
+#stats(10)
+
+---
+// The page count comes from the page counter instead.
+// Ref: false
+#locate(loc => test(counter(page).final(loc).first(), 1))
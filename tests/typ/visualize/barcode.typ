@@ -0,0 +1,21 @@
+// Test barcodes.
+
+---
+// Error: 2-26 barcode has an invalid check digit (expected 1)
+#barcode("4006381333932")
+
+---
+// Error: 2-19 barcode must only contain digits
+#barcode("40063a")
+
+---
+// Error: 2-18 barcode must have 12 or 13 digits
+#barcode("12345")
+
+---
+// Error: 2-31 barcode must have 7 or 8 digits
+#barcode(kind: "ean8", "1234")
+
+---
+// Error: 2-35 code 128 barcode must only contain printable ASCII characters
+#barcode(kind: "code128", "Grüße")
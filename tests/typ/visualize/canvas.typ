@@ -0,0 +1,38 @@
+// Test canvases.
+
+---
+// Shapes are drawn on top of each other and scaled by the unit.
+// Ref: false
+#style(styles => {
+  let size = measure(canvas(1cm, {
+    line(end: (3pt, 1pt))
+    line(end: (1pt, 2pt))
+  }), styles)
+  test(calc.round(size.width / 1cm, digits: 3), 3.0)
+  test(calc.round(size.height / 1cm, digits: 3), 2.0)
+})
+
+---
+// Error: 2-21 canvas unit must be positive
+#canvas(0pt, line())
+
+---
+// Text is neither moved nor scaled by the unit.
+// Ref: false
+#set page(width: auto, height: auto, margin: 0pt)
+#canvas(1cm, {
+  line(end: (1pt, 1pt))
+  strong[Hi#metadata(none)<end>]
+})
+
+#locate(loc => style(styles => {
+  let width = measure(strong[Hi], styles).width
+  let pos = query(<end>, loc).first().location().position()
+  test(calc.abs((pos.x - width) / 1pt) < 0.01, true)
+  test(pos.y < 1cm, true)
+}))
+
+#style(styles => {
+  let size = measure(canvas(1cm, strong[Hi]), styles)
+  test(size, measure(strong[Hi], styles))
+})
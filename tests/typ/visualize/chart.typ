@@ -0,0 +1,24 @@
+// Test charts.
+
+---
+// Ref: false
+#chart(kind: "bar", labels: ([A], [B]), legend: ([X], [Y]), (1, 2), (3, 4))
+#chart(kind: "line", (1, 4, 2), (2, 3, 5))
+#chart(kind: "scatter", ((0, 1), (1.5, 3), (4, 2)))
+#chart(kind: "pie", labels: ([A], [B]), (1, 2))
+
+---
+// Error: 2-9 chart must contain data
+#chart()
+
+---
+// Error: 2-38 bar charts only support plain numbers
+#chart(kind: "bar", ((1, 2), (2, 3)))
+
+---
+// Error: 2-29 pie chart values must not be negative
+#chart(kind: "pie", (1, -2))
+
+---
+// Error: 8-20 data points must be numbers or pairs of numbers
+#chart(((1, 2, 3),))
@@ -0,0 +1,12 @@
+// Test anchors and connectors.
+
+---
+// Ref: false
+#connect(<a>, <b>, arrow: true, stroke: blue)
+From #anchor() <a> here \
+to #anchor() <b> there.
+
+---
+#anchor() <start>
+// Error: 2-28 label `<missing>` does not exist in the document
+#connect(<start>, <missing>)
@@ -0,0 +1,5 @@
+// Test QR codes.
+
+---
+// Error: 2-20 data is too long for a QR code
+#qrcode("a" * 8000)
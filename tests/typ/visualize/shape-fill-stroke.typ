@@ -47,18 +47,21 @@
   dir: ltr,
   square(
     stroke: (left: red, top: yellow, right: green, bottom: blue),
+    // Warning: 41-42 font family "Roboto" has no variant with weight 700, using weight 400 instead
     radius: 50%, align(center+horizon)[*G*],
     inset: 8pt
   ),
   h(0.5cm),
   square(
     stroke: (left: red, top: yellow + 8pt, right: green, bottom: blue + 2pt),
+    // Warning: 41-42 font family "Roboto" has no variant with weight 700, using weight 400 instead
     radius: 50%, align(center+horizon)[*G*],
     inset: 8pt
   ),
   h(0.5cm),
   square(
     stroke: (left: red, top: yellow, right: green, bottom: blue),
+    // Warning: 42-43 font family "Roboto" has no variant with weight 700, using weight 400 instead
     radius: 100%, align(center+horizon)[*G*],
     inset: 8pt
   ),